@@ -0,0 +1,251 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+
+/// Schema version written by this launcher; bump it together with a new migration.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+pub const DEFAULT_CONSOLE_PORT: u16 = 9001;
+/// Access and secret key RustFS falls back to when none are given.
+pub const DEFAULT_CREDENTIAL: &str = "rustfsadmin";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    #[default]
+    Never,
+    OnFailure,
+    Always,
+}
+
+impl RestartPolicy {
+    /// The serialized name, as used in config files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RestartPolicy::Never => "never",
+            RestartPolicy::OnFailure => "on-failure",
+            RestartPolicy::Always => "always",
+        }
+    }
+
+    /// Parses a serialized name, falling back to `Never`.
+    pub fn from_value(value: &str) -> Self {
+        match value {
+            "on-failure" => RestartPolicy::OnFailure,
+            "always" => RestartPolicy::Always,
+            _ => RestartPolicy::Never,
+        }
+    }
+}
+
+/// How the access and secret key reach the RustFS process.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum CredentialDelivery {
+    /// `RUSTFS_ACCESS_KEY` / `RUSTFS_SECRET_KEY`, readable only by the same user.
+    #[default]
+    Env,
+    /// `--access-key` / `--secret-key`, visible to every local user in `ps`.
+    Argv,
+}
+
+impl CredentialDelivery {
+    /// The serialized name, as used in config files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CredentialDelivery::Env => "env",
+            CredentialDelivery::Argv => "argv",
+        }
+    }
+
+    /// Parses a serialized name, falling back to `Env`.
+    pub fn from_value(value: &str) -> Self {
+        match value {
+            "argv" => CredentialDelivery::Argv,
+            _ => CredentialDelivery::Env,
+        }
+    }
+}
+
+/// Where saved secret keys are kept.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum SecretBackend {
+    /// The platform credential store (Keychain, Credential Manager, Secret Service).
+    #[default]
+    Keyring,
+    /// A passphrase-encrypted file in the launcher's config directory.
+    Vault,
+}
+
+impl SecretBackend {
+    /// The serialized name, as used in config files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecretBackend::Keyring => "keyring",
+            SecretBackend::Vault => "vault",
+        }
+    }
+
+    /// Parses a serialized name, falling back to `Keyring`.
+    pub fn from_value(value: &str) -> Self {
+        match value {
+            "vault" => SecretBackend::Vault,
+            _ => SecretBackend::Keyring,
+        }
+    }
+}
+
+/// Points at a secret key saved outside the config file.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SecretRef {
+    pub backend: SecretBackend,
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct RustFsConfig {
+    pub schema_version: u32,
+    pub binary_path: Option<String>,
+    /// Data directories or ellipsis patterns like `/data/vol{1...4}`, passed to RustFS in order.
+    pub data_volumes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Pick a free port at launch, scanning upward from `port`.
+    pub auto_port: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_key: Option<String>,
+    /// Saved secret key, used when `secret_key` is unset and resolved just before spawning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_ref: Option<SecretRef>,
+    /// Where a newly entered secret key is saved.
+    pub secret_backend: SecretBackend,
+    pub credential_delivery: CredentialDelivery,
+    pub console_enable: bool,
+    /// Console listen host; defaults to `host`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub console_host: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub console_port: Option<u16>,
+    /// Directory holding the TLS certificate and key RustFS serves HTTPS with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// Domain names for virtual-host-style bucket requests.
+    pub server_domains: Vec<String>,
+    /// OpenTelemetry collector endpoint for RustFS observability data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub obs_endpoint: Option<String>,
+    /// Seconds to wait after the graceful stop signal before killing RustFS.
+    pub shutdown_timeout_secs: u64,
+    pub restart_policy: RestartPolicy,
+    pub max_restarts: u32,
+    /// Initial restart delay; doubles after every consecutive failure.
+    pub restart_backoff_secs: u64,
+    /// Seconds to wait for the endpoint to answer before a launch counts as failed.
+    pub ready_timeout_secs: u64,
+    pub health_interval_secs: u64,
+    /// Additional arguments appended after the ones the launcher manages.
+    pub extra_args: Vec<String>,
+    /// Additional environment variables for the RustFS process.
+    pub env: BTreeMap<String, String>,
+}
+
+impl Default for RustFsConfig {
+    fn default() -> Self {
+        Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            binary_path: None,
+            data_volumes: Vec::new(),
+            port: Some(9000),
+            auto_port: false,
+            host: Some("127.0.0.1".to_string()),
+            access_key: Some(DEFAULT_CREDENTIAL.to_string()),
+            secret_key: Some(DEFAULT_CREDENTIAL.to_string()),
+            secret_ref: None,
+            secret_backend: SecretBackend::Keyring,
+            credential_delivery: CredentialDelivery::Env,
+            console_enable: false,
+            console_host: None,
+            console_port: None,
+            tls_path: None,
+            region: None,
+            server_domains: Vec::new(),
+            obs_endpoint: None,
+            shutdown_timeout_secs: 10,
+            restart_policy: RestartPolicy::Never,
+            max_restarts: 5,
+            restart_backoff_secs: 1,
+            ready_timeout_secs: 30,
+            health_interval_secs: 5,
+            extra_args: Vec::new(),
+            env: BTreeMap::new(),
+        }
+    }
+}
+
+impl RustFsConfig {
+    pub fn address(&self) -> String {
+        format!(
+            "{}:{}",
+            self.host.as_deref().unwrap_or("127.0.0.1"),
+            self.port.unwrap_or(9000)
+        )
+    }
+
+    pub fn console_port(&self) -> u16 {
+        self.console_port.unwrap_or(DEFAULT_CONSOLE_PORT)
+    }
+
+    pub fn console_address(&self) -> String {
+        let host = self
+            .console_host
+            .as_deref()
+            .or(self.host.as_deref())
+            .unwrap_or("127.0.0.1");
+        format!("{}:{}", host, self.console_port())
+    }
+
+    /// Domains with surrounding whitespace and empty entries removed.
+    pub fn server_domains(&self) -> Vec<&str> {
+        self.server_domains
+            .iter()
+            .map(|domain| domain.trim())
+            .filter(|domain| !domain.is_empty())
+            .collect()
+    }
+
+    /// Whether the S3 or console listener accepts connections from other machines.
+    pub fn is_exposed(&self) -> bool {
+        let host = self.host.as_deref().unwrap_or("127.0.0.1");
+        let console_host = self.console_host.as_deref().unwrap_or(host);
+        !is_loopback_host(host) || (self.console_enable && !is_loopback_host(console_host))
+    }
+
+    /// Whether either key is unset or still the public default.
+    pub fn uses_default_credentials(&self) -> bool {
+        let access_default =
+            self.access_key.as_deref().unwrap_or(DEFAULT_CREDENTIAL) == DEFAULT_CREDENTIAL;
+        let secret_default = match (&self.secret_key, &self.secret_ref) {
+            (Some(secret_key), _) => secret_key == DEFAULT_CREDENTIAL,
+            (None, secret_ref) => secret_ref.is_none(),
+        };
+        access_default || secret_default
+    }
+}
+
+/// Whether `host` only accepts local connections: `localhost` or a loopback IP.
+pub fn is_loopback_host(host: &str) -> bool {
+    let host = host
+        .strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host);
+    host.eq_ignore_ascii_case("localhost")
+        || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
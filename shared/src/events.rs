@@ -0,0 +1,10 @@
+//! Names of the events the backend emits to the main window.
+
+/// Payload: [`LogEntry`](crate::log::LogEntry)
+pub const APP_LOG: &str = "app-log";
+/// Payload: [`LogEntry`](crate::log::LogEntry)
+pub const RUSTFS_LOG: &str = "rustfs-log";
+/// Payload: [`RustFsStatus`](crate::status::RustFsStatus)
+pub const RUSTFS_STATUS: &str = "rustfs-status";
+/// Payload: [`HealthUpdate`](crate::health::HealthUpdate)
+pub const RUSTFS_HEALTH: &str = "rustfs-health";
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unreachable,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProbeResult {
+    pub timestamp: String,
+    pub target: String,
+    pub address: String,
+    pub ok: bool,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealthUpdate {
+    pub status: HealthStatus,
+    pub consecutive_failures: u32,
+    pub latency_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealthReport {
+    pub status: Option<HealthStatus>,
+    pub consecutive_failures: u32,
+    pub results: Vec<ProbeResult>,
+}
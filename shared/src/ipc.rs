@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommandResponse {
+    pub success: bool,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+/// Failures the UI reacts to beyond showing their message.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    AlreadyRunning,
+    ProcessBusy,
+    NotRunning,
+    #[serde(other)]
+    Other,
+}
+
+/// What a failed command rejects with.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CommandError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+/// A problem with one config field; `field` uses the `RustFsConfig` field name.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ProfileList {
+    pub names: Vec<String>,
+    pub last_used: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Credentials {
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+pub struct VaultStatus {
+    /// A vault file has been created.
+    pub exists: bool,
+    /// The passphrase was entered this session.
+    pub unlocked: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TlsInfo {
+    /// Directory to pass to RustFS as `--tls-path`.
+    pub tls_path: String,
+    /// SHA-256 fingerprint of the local CA certificate.
+    pub ca_fingerprint: String,
+    /// Names and addresses the server certificate is valid for.
+    pub hosts: Vec<String>,
+}
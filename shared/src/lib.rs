@@ -0,0 +1,9 @@
+//! Types exchanged over IPC between the launcher UI (wasm) and the Tauri backend.
+
+pub mod config;
+pub mod events;
+pub mod health;
+pub mod ipc;
+pub mod log;
+pub mod redact;
+pub mod status;
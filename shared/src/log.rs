@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// One line of the app or RustFS log.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LogEntry {
+    /// Local wall-clock time, `HH:MM:SS`.
+    pub time: String,
+    pub message: String,
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.time, self.message)
+    }
+}
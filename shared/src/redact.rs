@@ -0,0 +1,188 @@
+//! Masks credentials before a message reaches a log buffer.
+
+pub const MASK: &str = "******";
+
+// Flags whose following argument is a credential
+const SECRET_FLAGS: &[&str] = &["--access-key", "--secret-key"];
+
+// Config fields and environment variables holding credentials
+const SECRET_FIELDS: &[&str] = &[
+    "access_key",
+    "secret_key",
+    "RUSTFS_ACCESS_KEY",
+    "RUSTFS_SECRET_KEY",
+];
+
+/// Replaces the value after any known secret flag or field with [`MASK`].
+///
+/// Handles plain arguments (`--secret-key value`, `--secret-key=value`), the quoted
+/// form used by `Command`'s `Debug` output, `Debug` structs (`secret_key: Some("value")`)
+/// and JSON (`"secret_key":"value"`). Fields need a `:` or `=` before their value, so prose
+/// such as `Invalid access_key length` is left as it is; flags also take the next word.
+/// Unquoted values end at `&` or `;`, so query strings keep their other parameters.
+pub fn redact(message: &str) -> String {
+    let mut output = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some((start, key)) = next_key(rest) {
+        let after_key = start + key.len();
+        let flag = key.starts_with("--");
+        output.push_str(&rest[..after_key]);
+        rest = &rest[after_key..];
+        if let Some(consumed) = mask_value(rest, flag, &mut output) {
+            rest = &rest[consumed..];
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Finds the earliest secret flag or field in `text`.
+fn next_key(text: &str) -> Option<(usize, &'static str)> {
+    SECRET_FLAGS
+        .iter()
+        .chain(SECRET_FIELDS)
+        .filter_map(|key| text.find(key).map(|start| (start, *key)))
+        .min_by_key(|(start, key)| (*start, std::cmp::Reverse(key.len())))
+}
+
+/// Writes the separator and masked value that follow a key, returning the bytes consumed.
+///
+/// Returns `None`, writing nothing, when the key is not followed by a value, e.g. the
+/// `secret_key` in `secret_key_path` or in `secret_key: None`. A bare space only separates
+/// a flag from its value.
+fn mask_value(text: &str, flag: bool, output: &mut String) -> Option<usize> {
+    // The closing quote of a quoted key
+    let mut pos = usize::from(text.starts_with('"'));
+    let space_len = |text: &str| {
+        text.find(|c: char| !matches!(c, ' ' | '\t'))
+            .unwrap_or(text.len())
+    };
+    let leading = space_len(&text[pos..]);
+    pos += leading;
+    if text[pos..].starts_with([':', '=']) {
+        pos += 1;
+        pos += space_len(&text[pos..]);
+    } else if !flag || leading == 0 {
+        return None;
+    }
+    if text[pos..].starts_with("Some(") {
+        pos += "Some(".len();
+    }
+
+    let value = &text[pos..];
+    let (prefix, value_len, suffix) = if let Some(quoted) = value.strip_prefix('"') {
+        let len = quoted_len(quoted);
+        ("\"", len, if len < quoted.len() { "\"" } else { "" })
+    } else {
+        let len = value
+            .find(|c: char| {
+                c.is_whitespace() || matches!(c, ',' | ')' | '}' | ']' | '"' | '&' | ';')
+            })
+            .unwrap_or(value.len());
+        ("", len, "")
+    };
+    let raw = &value[prefix.len()..prefix.len() + value_len];
+    if raw.is_empty() || raw == "None" || raw == "null" {
+        return None;
+    }
+
+    output.push_str(&text[..pos]);
+    output.push_str(prefix);
+    output.push_str(MASK);
+    output.push_str(suffix);
+    Some(pos + prefix.len() + value_len + suffix.len())
+}
+
+/// Length of a quoted string's contents, up to the first unescaped `"`.
+fn quoted_len(text: &str) -> usize {
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return i,
+            _ => escaped = false,
+        }
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_flag_arguments() {
+        assert_eq!(
+            redact("rustfs --access-key admin --secret-key s3cr3t /data"),
+            "rustfs --access-key ****** --secret-key ****** /data"
+        );
+        assert_eq!(
+            redact("rustfs --secret-key=s3cr3t --region us-east-1"),
+            "rustfs --secret-key=****** --region us-east-1"
+        );
+        assert_eq!(redact("--secret-key s3cr3t"), "--secret-key ******");
+        assert_eq!(
+            redact("args: --access-key admin"),
+            "args: --access-key ******"
+        );
+    }
+
+    #[test]
+    fn masks_query_parameters() {
+        assert_eq!(
+            redact("GET /probe?access_key=abc&secret_key=def&region=us-east-1"),
+            "GET /probe?access_key=******&secret_key=******&region=us-east-1"
+        );
+        assert_eq!(
+            redact("access_key=abc; secret_key=def;"),
+            "access_key=******; secret_key=******;"
+        );
+    }
+
+    #[test]
+    fn masks_command_debug_output() {
+        assert_eq!(
+            redact(
+                r#"Spawning command: "rustfs" "--access-key" "admin" "--secret-key" "a \"quoted\" key" "/data""#
+            ),
+            r#"Spawning command: "rustfs" "--access-key" "******" "--secret-key" "******" "/data""#
+        );
+        assert_eq!(
+            redact(r#"RUSTFS_SECRET_KEY="s3cr3t" "rustfs" "server""#),
+            r#"RUSTFS_SECRET_KEY="******" "rustfs" "server""#
+        );
+    }
+
+    #[test]
+    fn masks_struct_and_json_fields() {
+        assert_eq!(
+            redact(
+                r#"Config: RustFsConfig { access_key: Some("admin"), secret_key: Some("s3cr3t"), tls_path: None }"#
+            ),
+            r#"Config: RustFsConfig { access_key: Some("******"), secret_key: Some("******"), tls_path: None }"#
+        );
+        assert_eq!(
+            redact(r#"{"access_key":"admin","secret_key":"s3cr3t","port":9000}"#),
+            r#"{"access_key":"******","secret_key":"******","port":9000}"#
+        );
+        assert_eq!(
+            redact(r#"env: {"RUSTFS_ACCESS_KEY": "admin"}"#),
+            r#"env: {"RUSTFS_ACCESS_KEY": "******"}"#
+        );
+    }
+
+    #[test]
+    fn leaves_other_text_alone() {
+        for message in [
+            "RustFS started on 127.0.0.1:9000",
+            "secret_key: None, access_key: null",
+            "secret_key_path: /etc/rustfs/key",
+            "Missing value for --secret-key",
+            "Invalid access_key length",
+            "The secret_key field was left empty",
+            "",
+        ] {
+            assert_eq!(redact(message), message);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum RustFsStatus {
+    Stopped,
+    Starting,
+    Running {
+        pid: u32,
+    },
+    Exited {
+        code: Option<i32>,
+    },
+    Crashed {
+        code: Option<i32>,
+        signal: Option<i32>,
+    },
+    Restarting {
+        attempt: u32,
+        delay_secs: u64,
+    },
+    GaveUp {
+        restarts: u32,
+    },
+}
+
+impl RustFsStatus {
+    /// Whether a process is running or about to be (re)started.
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            RustFsStatus::Starting | RustFsStatus::Running { .. } | RustFsStatus::Restarting { .. }
+        )
+    }
+}
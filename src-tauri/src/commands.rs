@@ -1,20 +1,48 @@
-use crate::config::RustFsConfig;
+use crate::credentials;
 use crate::error::{Error, Result};
+use crate::health;
+use crate::portable;
+use crate::ports;
 use crate::process;
+use crate::secrets;
 use crate::state;
-use serde::Serialize;
+use crate::store;
+use crate::tls;
+use crate::validate;
+use crate::vault;
+use crate::volumes;
+use rustfs_launcher_shared::config::RustFsConfig;
+use rustfs_launcher_shared::health::HealthReport;
+use rustfs_launcher_shared::ipc::{
+    CommandResponse, Credentials, FieldError, ProfileList, TlsInfo, VaultStatus,
+};
+use rustfs_launcher_shared::log::LogEntry;
+use rustfs_launcher_shared::status::RustFsStatus;
 use std::io::{Error as IoError, ErrorKind};
-use tauri::async_runtime;
+use tauri::{async_runtime, AppHandle};
 
-#[derive(Debug, Serialize)]
-pub struct CommandResponse {
-    pub success: bool,
-    pub message: String,
+#[tauri::command]
+pub async fn launch_rustfs(app: AppHandle, config: RustFsConfig) -> Result<CommandResponse> {
+    let dir = store::config_dir(&app)?;
+    let handle = async_runtime::spawn_blocking(move || {
+        tls::refresh(&dir, &config)?;
+        process::launch(config)
+    });
+    let launched = handle.await.map_err(|err| {
+        let io_error = IoError::new(ErrorKind::Other, err.to_string());
+        Error::Io(io_error)
+    })??;
+
+    Ok(CommandResponse {
+        success: true,
+        message: format!("RustFS launched with PID: {}", launched.pid),
+        address: Some(launched.address),
+    })
 }
 
 #[tauri::command]
-pub async fn launch_rustfs(config: RustFsConfig) -> Result<CommandResponse> {
-    let handle = async_runtime::spawn_blocking(move || process::launch(config));
+pub async fn stop_rustfs() -> Result<CommandResponse> {
+    let handle = async_runtime::spawn_blocking(process::stop);
     let message = handle.await.map_err(|err| {
         let io_error = IoError::new(ErrorKind::Other, err.to_string());
         Error::Io(io_error)
@@ -23,18 +51,64 @@ pub async fn launch_rustfs(config: RustFsConfig) -> Result<CommandResponse> {
     Ok(CommandResponse {
         success: true,
         message,
+        address: None,
     })
 }
 
 #[tauri::command]
-pub async fn validate_config(config: RustFsConfig) -> Result<bool> {
-    if config.data_path.is_empty() {
-        return Err(Error::DataPathRequired);
-    }
-    if !std::path::Path::new(&config.data_path).exists() {
-        return Err(Error::DataPathNotExist(config.data_path));
+pub async fn restart_rustfs(app: AppHandle, config: RustFsConfig) -> Result<CommandResponse> {
+    let dir = store::config_dir(&app)?;
+    let handle = async_runtime::spawn_blocking(move || {
+        tls::refresh(&dir, &config)?;
+        process::restart(config)
+    });
+    let launched = handle.await.map_err(|err| {
+        let io_error = IoError::new(ErrorKind::Other, err.to_string());
+        Error::Io(io_error)
+    })??;
+
+    Ok(CommandResponse {
+        success: true,
+        message: format!("RustFS restarted with PID: {}", launched.pid),
+        address: Some(launched.address),
+    })
+}
+
+#[tauri::command]
+pub async fn validate_config(config: RustFsConfig) -> Result<Vec<FieldError>> {
+    let mut errors = validate::validate(&config);
+    // A running server holds its own ports and auto_port picks a free one at launch
+    if !config.auto_port && state::running_rustfs_pid().is_none() {
+        errors.extend(validate::check_ports(&config));
     }
-    Ok(true)
+    Ok(errors)
+}
+
+#[tauri::command]
+pub async fn find_free_port(host: String, preferred: u16) -> Result<u16> {
+    let handle =
+        async_runtime::spawn_blocking(move || ports::find_free_port(&host, preferred, None));
+    handle.await.map_err(|err| {
+        let io_error = IoError::new(ErrorKind::Other, err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn create_data_volumes(pattern: String) -> Result<Vec<String>> {
+    let volumes = volumes::create(&pattern)?;
+    state::add_app_log(format!(
+        "Created {} data volume(s) from {}",
+        volumes.len(),
+        pattern
+    ));
+    Ok(volumes)
+}
+
+#[tauri::command]
+pub async fn generate_credentials() -> Result<Credentials> {
+    state::add_app_log("Generated new access and secret keys".to_string());
+    Ok(credentials::generate())
 }
 
 #[tauri::command]
@@ -48,15 +122,153 @@ pub async fn diagnose_rustfs_binary() -> Result<CommandResponse> {
     Ok(CommandResponse {
         success: true,
         message,
+        address: None,
     })
 }
 
 #[tauri::command]
-pub async fn get_app_logs() -> Result<Vec<String>> {
+pub async fn get_app_logs() -> Result<Vec<LogEntry>> {
     Ok(state::get_app_logs())
 }
 
 #[tauri::command]
-pub async fn get_rustfs_logs() -> Result<Vec<String>> {
+pub async fn get_rustfs_logs() -> Result<Vec<LogEntry>> {
     Ok(state::get_rustfs_logs())
 }
+
+#[tauri::command]
+pub async fn get_rustfs_status() -> Result<RustFsStatus> {
+    Ok(state::get_rustfs_status())
+}
+
+#[tauri::command]
+pub async fn get_health(limit: Option<usize>) -> Result<HealthReport> {
+    Ok(health::report(limit.unwrap_or(50)))
+}
+
+#[tauri::command]
+pub async fn load_config(app: AppHandle) -> Result<Option<RustFsConfig>> {
+    let dir = store::config_dir(&app)?;
+    Ok(store::load_profiles(&dir)?.active().cloned())
+}
+
+#[tauri::command]
+pub async fn save_config(app: AppHandle, mut config: RustFsConfig) -> Result<()> {
+    let dir = store::config_dir(&app)?;
+    store::update_profiles(&dir, |profiles| {
+        let previous = profiles
+            .active()
+            .and_then(|active| active.secret_ref.clone());
+        secrets::seal(&mut config, previous)?;
+        profiles.save_active(config);
+        Ok(())
+    })
+}
+
+#[tauri::command]
+pub async fn list_profiles(app: AppHandle) -> Result<ProfileList> {
+    let dir = store::config_dir(&app)?;
+    Ok(store::load_profiles(&dir)?.list())
+}
+
+#[tauri::command]
+pub async fn load_profile(app: AppHandle, name: String) -> Result<RustFsConfig> {
+    let dir = store::config_dir(&app)?;
+    store::update_profiles(&dir, |profiles| profiles.select(&name))
+}
+
+#[tauri::command]
+pub async fn create_profile(app: AppHandle, name: String, mut config: RustFsConfig) -> Result<()> {
+    let dir = store::config_dir(&app)?;
+    store::update_profiles(&dir, |profiles| {
+        secrets::detach(&mut config)?;
+        profiles.create(&name, config)
+    })
+}
+
+#[tauri::command]
+pub async fn rename_profile(app: AppHandle, name: String, new_name: String) -> Result<()> {
+    let dir = store::config_dir(&app)?;
+    store::update_profiles(&dir, |profiles| profiles.rename(&name, &new_name))
+}
+
+#[tauri::command]
+pub async fn duplicate_profile(app: AppHandle, name: String, new_name: String) -> Result<()> {
+    let dir = store::config_dir(&app)?;
+    store::update_profiles(&dir, |profiles| {
+        secrets::detach(profiles.duplicate(&name, &new_name)?)
+    })
+}
+
+#[tauri::command]
+pub async fn delete_profile(app: AppHandle, name: String) -> Result<()> {
+    let dir = store::config_dir(&app)?;
+    store::update_profiles(&dir, |profiles| {
+        if let Some(secret_ref) = profiles.delete(&name)?.secret_ref {
+            secrets::forget(&secret_ref);
+        }
+        Ok(())
+    })
+}
+
+#[tauri::command]
+pub async fn export_config(
+    path: String,
+    config: RustFsConfig,
+    include_secrets: bool,
+) -> Result<()> {
+    portable::export(std::path::Path::new(&path), config, include_secrets)
+}
+
+#[tauri::command]
+pub async fn import_config(path: String) -> Result<RustFsConfig> {
+    portable::import(std::path::Path::new(&path))
+}
+
+#[tauri::command]
+pub async fn vault_status(app: AppHandle) -> Result<VaultStatus> {
+    let dir = store::config_dir(&app)?;
+    Ok(vault::status(&dir))
+}
+
+#[tauri::command]
+pub async fn unlock_vault(app: AppHandle, passphrase: String) -> Result<()> {
+    let dir = store::config_dir(&app)?;
+    // Key derivation is deliberately slow
+    let handle = async_runtime::spawn_blocking(move || vault::unlock(&dir, &passphrase));
+    handle.await.map_err(|err| {
+        let io_error = IoError::new(ErrorKind::Other, err.to_string());
+        Error::Io(io_error)
+    })??;
+    state::add_app_log("Secret vault unlocked".to_string());
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn lock_vault() -> Result<()> {
+    vault::lock();
+    state::add_app_log("Secret vault locked".to_string());
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn generate_tls_certificate(app: AppHandle, host: String) -> Result<TlsInfo> {
+    let dir = store::config_dir(&app)?;
+    let handle = async_runtime::spawn_blocking(move || tls::generate(&dir, &host));
+    handle.await.map_err(|err| {
+        let io_error = IoError::new(ErrorKind::Other, err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn tls_ca_fingerprint(app: AppHandle) -> Result<Option<String>> {
+    let dir = store::config_dir(&app)?;
+    tls::ca_fingerprint(&dir)
+}
+
+#[tauri::command]
+pub async fn export_tls_ca(app: AppHandle, path: String) -> Result<()> {
+    let dir = store::config_dir(&app)?;
+    tls::export_ca(&dir, std::path::Path::new(&path))
+}
@@ -0,0 +1,69 @@
+use crate::validate::{ACCESS_KEY_LEN, SECRET_KEY_LEN};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::OsRng;
+use rustfs_launcher_shared::ipc::Credentials;
+
+// Alphanumerics only, so keys survive shells, URLs and config files unquoted
+const ACCESS_KEY_CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+const SECRET_KEY_CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Generates the longest access and secret keys RustFS accepts from the OS CSPRNG.
+pub fn generate() -> Credentials {
+    Credentials {
+        access_key: random_string(ACCESS_KEY_CHARSET, *ACCESS_KEY_LEN.end()),
+        secret_key: random_string(SECRET_KEY_CHARSET, *SECRET_KEY_LEN.end()),
+    }
+}
+
+fn random_string(charset: &[u8], len: usize) -> String {
+    // Values at or above the last whole multiple of the charset size are redrawn,
+    // so every character is equally likely
+    let size = charset.len() as u32;
+    let zone = u32::MAX - u32::MAX % size;
+    (0..len)
+        .map(|_| loop {
+            let value = OsRng.next_u32();
+            if value < zone {
+                break charset[(value % size) as usize] as char;
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate;
+    use rustfs_launcher_shared::config::RustFsConfig;
+
+    #[test]
+    fn keys_fit_rustfs_rules() {
+        let credentials = generate();
+        assert!(ACCESS_KEY_LEN.contains(&credentials.access_key.len()));
+        assert!(SECRET_KEY_LEN.contains(&credentials.secret_key.len()));
+        assert!(credentials
+            .access_key
+            .bytes()
+            .all(|byte| ACCESS_KEY_CHARSET.contains(&byte)));
+        assert!(credentials
+            .secret_key
+            .bytes()
+            .all(|byte| SECRET_KEY_CHARSET.contains(&byte)));
+        assert_ne!(generate().secret_key, credentials.secret_key);
+    }
+
+    #[test]
+    fn generated_keys_may_be_exposed() {
+        let dir = tempfile::tempdir().unwrap();
+        let credentials = generate();
+        let config = RustFsConfig {
+            data_volumes: vec![dir.path().display().to_string()],
+            host: Some("0.0.0.0".to_string()),
+            access_key: Some(credentials.access_key),
+            secret_key: Some(credentials.secret_key),
+            ..RustFsConfig::default()
+        };
+        assert!(!config.uses_default_credentials());
+        assert!(validate::validate(&config).is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::ports::PortOwner;
+use rustfs_launcher_shared::ipc::{CommandError, ErrorCode, FieldError};
 use serde::{Serialize, Serializer};
 
 #[derive(Debug, thiserror::Error)]
@@ -5,11 +7,71 @@ pub enum Error {
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
-    #[error("Data path is required")]
-    DataPathRequired,
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("Failed to write TOML: {0}")]
+    TomlSerialize(#[from] toml::ser::Error),
+
+    #[error("Failed to read TOML: {0}")]
+    TomlDeserialize(#[from] toml::de::Error),
+
+    #[error("Configuration file version {0} is newer than this launcher supports")]
+    UnsupportedConfigVersion(u32),
+
+    #[error("Invalid configuration document: {0}")]
+    InvalidConfigDocument(String),
+
+    #[error("App config directory is unavailable: {0}")]
+    ConfigDirUnavailable(String),
+
+    #[error("Profile not found: {0}")]
+    ProfileNotFound(String),
+
+    #[error("Profile already exists: {0}")]
+    ProfileExists(String),
+
+    #[error("Invalid profile name: \"{0}\"")]
+    InvalidProfileName(String),
+
+    #[error("At least one data volume is required")]
+    DataVolumeRequired,
+
+    #[error("Data volume does not exist: {0}")]
+    DataVolumeNotExist(String),
+
+    #[error("Data volume is not writable: {0}: {1}")]
+    DataVolumeNotWritable(String, std::io::Error),
+
+    #[error("Data volume is listed more than once: {0}")]
+    DuplicateDataVolume(String),
+
+    #[error("Invalid volume pattern: {0} (expected e.g. /data/vol{{1...4}})")]
+    InvalidVolumePattern(String),
+
+    #[error("OS keyring error: {0}")]
+    Keyring(#[from] keyring::Error),
+
+    #[error("The secret vault is locked; unlock it with its passphrase first")]
+    VaultLocked,
+
+    #[error("Incorrect vault passphrase")]
+    VaultPassphrase,
+
+    #[error("Secret vault is damaged: {0}")]
+    VaultCorrupt(String),
+
+    #[error("Saved secret not found: {0}")]
+    SecretNotFound(String),
+
+    #[error("Failed to generate TLS certificate: {0}")]
+    Certificate(#[from] rcgen::Error),
 
-    #[error("Data path does not exist: {0}")]
-    DataPathNotExist(String),
+    #[error("No local TLS CA has been generated yet")]
+    TlsCaMissing,
+
+    #[error("Invalid configuration:{}", format_field_errors(.0))]
+    InvalidConfig(Vec<FieldError>),
 
     #[error("RustFS binary not found at {0}")]
     BinaryNotFound(String),
@@ -22,6 +84,59 @@ pub enum Error {
 
     #[error("RustFS binary failed with exit code: {0}")]
     BinaryFailed(String),
+
+    #[error("RustFS is not running")]
+    NotRunning,
+
+    #[error("RustFS is already running (PID {pid})")]
+    AlreadyRunning { pid: u32 },
+
+    #[error("RustFS is still starting or stopping; try again shortly")]
+    ProcessBusy,
+
+    #[error("RustFS did not become ready: {reason}{}", format_stderr(.stderr))]
+    NotReady { reason: String, stderr: Vec<String> },
+
+    #[error("Port {port} is already in use{}", format_owner(.owner))]
+    PortInUse { port: u16, owner: Option<PortOwner> },
+
+    #[error("No free port found at or above {0}")]
+    NoFreePort(u16),
+
+    #[error("Port was not released in time: {0}")]
+    PortNotReleased(String),
+}
+
+fn format_stderr(lines: &[String]) -> String {
+    if lines.is_empty() {
+        return String::new();
+    }
+    format!("\nLast stderr lines:\n{}", lines.join("\n"))
+}
+
+fn format_field_errors(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|error| format!("\n- {}", error.message))
+        .collect()
+}
+
+fn format_owner(owner: &Option<PortOwner>) -> String {
+    match owner {
+        Some(owner) => format!(" by PID {} ({})", owner.pid, owner.command),
+        None => String::new(),
+    }
+}
+
+impl Error {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::AlreadyRunning { .. } => ErrorCode::AlreadyRunning,
+            Error::ProcessBusy => ErrorCode::ProcessBusy,
+            Error::NotRunning => ErrorCode::NotRunning,
+            _ => ErrorCode::Other,
+        }
+    }
 }
 
 impl Serialize for Error {
@@ -29,7 +144,11 @@ impl Serialize for Error {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        CommandError {
+            code: self.code(),
+            message: self.to_string(),
+        }
+        .serialize(serializer)
     }
 }
 
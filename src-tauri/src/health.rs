@@ -0,0 +1,213 @@
+use crate::probe;
+use crate::state::{add_app_log, emit_event};
+use crate::supervisor;
+use lazy_static::lazy_static;
+use rustfs_launcher_shared::config::RustFsConfig;
+use rustfs_launcher_shared::events;
+use rustfs_launcher_shared::health::{HealthReport, HealthStatus, HealthUpdate, ProbeResult};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const HEALTH_HISTORY_CAPACITY: usize = 200;
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+const SLOW_PROBE: Duration = Duration::from_secs(1);
+const UNREACHABLE_AFTER_FAILURES: u32 = 3;
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Default)]
+struct HealthState {
+    status: Option<HealthStatus>,
+    consecutive_failures: u32,
+    history: VecDeque<ProbeResult>,
+}
+
+impl HealthState {
+    /// Folds one round of probe results, endpoint first, into the status and history.
+    fn record(&mut self, results: Vec<ProbeResult>) -> HealthUpdate {
+        let endpoint = &results[0];
+        let latency_ms = endpoint.latency_ms;
+        let all_ok = results.iter().all(|r| r.ok);
+        let slow = latency_ms.is_some_and(|ms| ms >= SLOW_PROBE.as_millis() as u64);
+
+        if endpoint.ok {
+            self.consecutive_failures = 0;
+        } else {
+            self.consecutive_failures += 1;
+        }
+
+        let status = if !endpoint.ok && self.consecutive_failures >= UNREACHABLE_AFTER_FAILURES {
+            HealthStatus::Unreachable
+        } else if !all_ok || slow {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+
+        self.status = Some(status);
+        for result in results {
+            self.history.push_back(result);
+            if self.history.len() > HEALTH_HISTORY_CAPACITY {
+                self.history.pop_front();
+            }
+        }
+
+        HealthUpdate {
+            status,
+            consecutive_failures: self.consecutive_failures,
+            latency_ms,
+        }
+    }
+
+    fn report(&self, limit: usize) -> HealthReport {
+        let skip = self.history.len().saturating_sub(limit);
+        HealthReport {
+            status: self.status,
+            consecutive_failures: self.consecutive_failures,
+            results: self.history.iter().skip(skip).cloned().collect(),
+        }
+    }
+}
+
+lazy_static! {
+    static ref HEALTH: Arc<Mutex<HealthState>> = Arc::new(Mutex::new(HealthState::default()));
+}
+
+/// Returns the current status and the last `limit` probe results, oldest first.
+pub fn report(limit: usize) -> HealthReport {
+    HEALTH.lock().unwrap().report(limit)
+}
+
+/// Probes the instance every `health_interval_secs` until the supervisor generation ends.
+pub fn start(generation: u64, config: RustFsConfig) {
+    {
+        let mut health = HEALTH.lock().unwrap();
+        health.status = None;
+        health.consecutive_failures = 0;
+    }
+
+    let interval = Duration::from_secs(config.health_interval_secs.max(1));
+    thread::spawn(move || loop {
+        if !sleep_while_current(generation, interval) {
+            return;
+        }
+        check(&config);
+    });
+}
+
+fn sleep_while_current(generation: u64, interval: Duration) -> bool {
+    let mut slept = Duration::ZERO;
+    while slept < interval {
+        if !supervisor::is_current(generation) {
+            return false;
+        }
+        thread::sleep(CANCEL_POLL_INTERVAL);
+        slept += CANCEL_POLL_INTERVAL;
+    }
+    supervisor::is_current(generation)
+}
+
+fn run_probe(target: &str, address: String, tls: bool) -> ProbeResult {
+    let outcome = match target {
+        "endpoint" => probe::endpoint(&address, tls, PROBE_TIMEOUT),
+        _ => probe::tcp(&address, PROBE_TIMEOUT),
+    };
+    ProbeResult {
+        timestamp: chrono::Local::now().to_rfc3339(),
+        target: target.to_string(),
+        address,
+        ok: outcome.is_ok(),
+        latency_ms: outcome.as_ref().ok().map(|d| d.as_millis() as u64),
+        error: outcome.err(),
+    }
+}
+
+fn check(config: &RustFsConfig) {
+    let tls = config.tls_path.is_some();
+    let mut results = vec![run_probe("endpoint", config.address(), tls)];
+    if config.console_enable {
+        results.push(run_probe("console", config.console_address(), tls));
+    }
+
+    let mut health = HEALTH.lock().unwrap();
+    let previous = health.status;
+    let update = health.record(results);
+    if previous != Some(update.status) {
+        add_app_log(format!(
+            "RustFS health changed to {:?} (consecutive failures: {})",
+            update.status, update.consecutive_failures
+        ));
+    }
+    drop(health);
+    emit_event(events::RUSTFS_HEALTH, update);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(target: &str, ok: bool, latency_ms: u64) -> ProbeResult {
+        ProbeResult {
+            timestamp: String::new(),
+            target: target.to_string(),
+            address: "127.0.0.1:9000".to_string(),
+            ok,
+            latency_ms: ok.then_some(latency_ms),
+            error: (!ok).then(|| "connection refused".to_string()),
+        }
+    }
+
+    #[test]
+    fn slow_or_partial_answers_are_degraded() {
+        let mut health = HealthState::default();
+        let update = health.record(vec![probe("endpoint", true, 20)]);
+        assert_eq!(update.status, HealthStatus::Healthy);
+        assert_eq!(update.latency_ms, Some(20));
+
+        let update = health.record(vec![probe("endpoint", true, 1500)]);
+        assert_eq!(update.status, HealthStatus::Degraded);
+
+        let update = health.record(vec![
+            probe("endpoint", true, 20),
+            probe("console", false, 0),
+        ]);
+        assert_eq!(update.status, HealthStatus::Degraded);
+        assert_eq!(update.consecutive_failures, 0);
+    }
+
+    #[test]
+    fn unreachable_after_repeated_failures() {
+        let mut health = HealthState::default();
+        for failures in 1..UNREACHABLE_AFTER_FAILURES {
+            let update = health.record(vec![probe("endpoint", false, 0)]);
+            assert_eq!(update.status, HealthStatus::Degraded);
+            assert_eq!(update.consecutive_failures, failures);
+        }
+        let update = health.record(vec![probe("endpoint", false, 0)]);
+        assert_eq!(update.status, HealthStatus::Unreachable);
+
+        let update = health.record(vec![probe("endpoint", true, 20)]);
+        assert_eq!(update.status, HealthStatus::Healthy);
+        assert_eq!(update.consecutive_failures, 0);
+    }
+
+    #[test]
+    fn history_is_capped_and_reported_oldest_first() {
+        let mut health = HealthState::default();
+        for latency in 0..HEALTH_HISTORY_CAPACITY as u64 + 10 {
+            health.record(vec![probe("endpoint", true, latency)]);
+        }
+        assert_eq!(health.history.len(), HEALTH_HISTORY_CAPACITY);
+
+        let report = health.report(3);
+        let latencies: Vec<_> = report.results.iter().map(|r| r.latency_ms).collect();
+        assert_eq!(latencies, [Some(207), Some(208), Some(209)]);
+        assert_eq!(report.status, Some(HealthStatus::Healthy));
+        assert_eq!(
+            health.report(usize::MAX).results.len(),
+            HEALTH_HISTORY_CAPACITY
+        );
+        assert!(HealthState::default().report(10).results.is_empty());
+    }
+}
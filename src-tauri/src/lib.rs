@@ -1,8 +1,21 @@
 mod commands;
-mod config;
+mod credentials;
 mod error;
+mod health;
+mod migrate;
+mod portable;
+mod ports;
+mod probe;
 mod process;
+mod secrets;
 mod state;
+mod status;
+mod store;
+mod supervisor;
+mod tls;
+mod validate;
+mod vault;
+mod volumes;
 
 use log;
 use state::{add_app_log, set_app_handle, terminate_rustfs_process};
@@ -34,14 +47,39 @@ pub fn run() {
         .on_window_event(|_window, event| {
             if let tauri::WindowEvent::CloseRequested { .. } = event {
                 add_app_log("Application closing, terminating RustFS process...".to_string());
+                // Otherwise a restart policy would treat the exit as a crash and respawn RustFS
+                supervisor::cancel();
                 terminate_rustfs_process();
             }
         })
         .invoke_handler(tauri::generate_handler![
             commands::launch_rustfs,
+            commands::stop_rustfs,
+            commands::restart_rustfs,
             commands::validate_config,
+            commands::find_free_port,
+            commands::create_data_volumes,
+            commands::generate_credentials,
             commands::get_app_logs,
             commands::get_rustfs_logs,
+            commands::get_rustfs_status,
+            commands::get_health,
+            commands::load_config,
+            commands::save_config,
+            commands::list_profiles,
+            commands::load_profile,
+            commands::create_profile,
+            commands::rename_profile,
+            commands::duplicate_profile,
+            commands::delete_profile,
+            commands::export_config,
+            commands::import_config,
+            commands::vault_status,
+            commands::unlock_vault,
+            commands::lock_vault,
+            commands::generate_tls_certificate,
+            commands::tls_ca_fingerprint,
+            commands::export_tls_ca,
             commands::diagnose_rustfs_binary
         ])
         .run(tauri::generate_context!())
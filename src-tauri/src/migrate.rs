@@ -0,0 +1,112 @@
+use crate::error::{Error, Result};
+use rustfs_launcher_shared::config::CURRENT_SCHEMA_VERSION;
+use serde_json::{Map, Value};
+
+const VERSION_KEY: &str = "schema_version";
+
+type Migration = fn(&mut Map<String, Value>);
+
+// MIGRATIONS[n] upgrades a document from version n to n + 1
+const MIGRATIONS: &[Migration] = &[v0_to_v1, v1_to_v2];
+
+/// Documents written before versioning kept the original field layout unchanged.
+fn v0_to_v1(_config: &mut Map<String, Value>) {}
+
+/// The single `data_path` became a list of data volumes.
+fn v1_to_v2(config: &mut Map<String, Value>) {
+    let volumes = match config.remove("data_path") {
+        Some(Value::String(path)) if !path.is_empty() => vec![Value::String(path)],
+        _ => Vec::new(),
+    };
+    config.insert("data_volumes".to_string(), Value::Array(volumes));
+}
+
+/// Returns the schema version of a serialized `RustFsConfig`, treating a missing field as 0.
+pub fn schema_version(config: &Value) -> u32 {
+    config
+        .get(VERSION_KEY)
+        .and_then(Value::as_u64)
+        .map(|version| version as u32)
+        .unwrap_or(0)
+}
+
+/// Upgrades a serialized `RustFsConfig` in place, one version at a time.
+///
+/// Returns the version the document had before migrating.
+pub fn migrate_config(config: &mut Value) -> Result<u32> {
+    let from = schema_version(config);
+    if from > CURRENT_SCHEMA_VERSION {
+        return Err(Error::UnsupportedConfigVersion(from));
+    }
+
+    let object = config
+        .as_object_mut()
+        .ok_or_else(|| Error::InvalidConfigDocument("expected a table of settings".to_string()))?;
+    for (version, migration) in MIGRATIONS.iter().enumerate().skip(from as usize) {
+        migration(object);
+        object.insert(VERSION_KEY.to_string(), Value::from(version as u32 + 1));
+    }
+    Ok(from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustfs_launcher_shared::config::RustFsConfig;
+
+    const CONFIG_V0: &str = include_str!("../fixtures/config_v0.json");
+    const CONFIG_V1: &str = include_str!("../fixtures/config_v1.json");
+
+    #[test]
+    fn migrates_unversioned_fixture_to_current() {
+        let mut value: Value = serde_json::from_str(CONFIG_V0).unwrap();
+        let from = migrate_config(&mut value).unwrap();
+        assert_eq!(from, 0);
+        assert_eq!(schema_version(&value), CURRENT_SCHEMA_VERSION);
+
+        let config: RustFsConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(config.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(config.data_volumes, ["/srv/rustfs/scratch"]);
+        assert_eq!(config.port, Some(9100));
+        assert_eq!(config.host.as_deref(), Some("0.0.0.0"));
+        assert_eq!(config.access_key.as_deref(), Some("scratchadmin"));
+        assert!(config.console_enable);
+    }
+
+    #[test]
+    fn migrates_data_path_to_data_volumes() {
+        let mut value: Value = serde_json::from_str(CONFIG_V1).unwrap();
+        assert_eq!(migrate_config(&mut value).unwrap(), 1);
+        assert!(value.get("data_path").is_none());
+
+        let config: RustFsConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(config.data_volumes, ["/srv/rustfs/erasure"]);
+        assert_eq!(config.port, Some(9300));
+
+        let mut empty = serde_json::json!({ "schema_version": 1, "data_path": "" });
+        migrate_config(&mut empty).unwrap();
+        assert_eq!(empty["data_volumes"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn current_documents_are_left_alone() {
+        let mut value = serde_json::to_value(RustFsConfig::default()).unwrap();
+        let before = value.clone();
+        assert_eq!(migrate_config(&mut value).unwrap(), CURRENT_SCHEMA_VERSION);
+        assert_eq!(value, before);
+    }
+
+    #[test]
+    fn rejects_documents_from_newer_launchers() {
+        let mut value = serde_json::json!({ "schema_version": CURRENT_SCHEMA_VERSION + 1 });
+        assert!(matches!(
+            migrate_config(&mut value),
+            Err(Error::UnsupportedConfigVersion(_))
+        ));
+    }
+
+    #[test]
+    fn every_version_has_a_migration() {
+        assert_eq!(MIGRATIONS.len(), CURRENT_SCHEMA_VERSION as usize);
+    }
+}
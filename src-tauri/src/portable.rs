@@ -0,0 +1,171 @@
+use crate::error::{Error, Result};
+use crate::migrate;
+use crate::secrets;
+use crate::state::add_app_log;
+use crate::store::write_atomic;
+use rustfs_launcher_shared::config::{RustFsConfig, CURRENT_SCHEMA_VERSION};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Version of the exported document layout, independent of the launcher version.
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PortableConfig {
+    version: u32,
+    config: RustFsConfig,
+}
+
+pub fn export(path: &Path, mut config: RustFsConfig, include_secrets: bool) -> Result<()> {
+    if include_secrets {
+        config = secrets::resolve(&config)?;
+    } else {
+        config.secret_key = None;
+        config.secret_ref = None;
+    }
+
+    let document = PortableConfig {
+        version: FORMAT_VERSION,
+        config,
+    };
+    let contents = toml::to_string_pretty(&document)?;
+    write_atomic(path, contents.as_bytes())?;
+    add_app_log(format!(
+        "Exported configuration to {} (secrets {})",
+        path.display(),
+        if include_secrets {
+            "included"
+        } else {
+            "omitted"
+        }
+    ));
+    Ok(())
+}
+
+pub fn import(path: &Path) -> Result<RustFsConfig> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut document: serde_json::Value = toml::from_str(&contents)?;
+    if let Some(config) = document.get_mut("config") {
+        let from = migrate::migrate_config(config)?;
+        if from < CURRENT_SCHEMA_VERSION {
+            add_app_log(format!(
+                "Upgraded imported configuration from schema version {}",
+                from
+            ));
+        }
+    }
+
+    // Left out of files exported without secrets; without this check the missing key
+    // would silently become the public default
+    let has_secret = document
+        .get("config")
+        .is_some_and(|config| config.get("secret_key").is_some());
+
+    let mut document: PortableConfig = serde_json::from_value(document)?;
+    if document.version > FORMAT_VERSION {
+        return Err(Error::UnsupportedConfigVersion(document.version));
+    }
+    // Saved secrets belong to the machine that exported the file
+    document.config.secret_ref = None;
+    if !has_secret {
+        document.config.secret_key = None;
+    }
+
+    add_app_log(format!("Imported configuration from {}", path.display()));
+    Ok(document.config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustfs_launcher_shared::config::{SecretBackend, SecretRef, DEFAULT_CREDENTIAL};
+
+    fn sample() -> RustFsConfig {
+        let mut config = RustFsConfig {
+            data_volumes: vec!["/data/vol{1...4}".to_string()],
+            host: Some("0.0.0.0".to_string()),
+            access_key: Some("launcher-access".to_string()),
+            secret_key: Some("launcher-secret".to_string()),
+            ..RustFsConfig::default()
+        };
+        config
+            .env
+            .insert("RUSTFS_OBS_LOGGER_LEVEL".to_string(), "debug".to_string());
+        config
+    }
+
+    #[test]
+    fn round_trips_with_secrets() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rustfs.toml");
+        export(&path, sample(), true).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with(&format!("version = {}", FORMAT_VERSION)));
+        assert!(contents.contains("[config.env]"));
+        assert!(contents.contains("launcher-secret"));
+
+        let imported = import(&path).unwrap();
+        assert_eq!(imported.data_volumes, ["/data/vol{1...4}"]);
+        assert_eq!(imported.access_key.as_deref(), Some("launcher-access"));
+        assert_eq!(imported.secret_key.as_deref(), Some("launcher-secret"));
+        assert_eq!(imported.env, sample().env);
+    }
+
+    #[test]
+    fn omitted_secrets_stay_unset() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rustfs.toml");
+        export(&path, sample(), false).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("launcher-secret"));
+        assert!(!contents.contains("secret_ref"));
+
+        let imported = import(&path).unwrap();
+        assert_ne!(imported.secret_key.as_deref(), Some(DEFAULT_CREDENTIAL));
+        assert_eq!(imported.secret_key, None);
+        assert_eq!(imported.secret_ref, None);
+        assert_eq!(imported.access_key.as_deref(), Some("launcher-access"));
+        assert_eq!(imported.env, sample().env);
+    }
+
+    #[test]
+    fn drops_saved_secret_references() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rustfs.toml");
+        let document = PortableConfig {
+            version: FORMAT_VERSION,
+            config: RustFsConfig {
+                secret_key: None,
+                secret_ref: Some(SecretRef {
+                    backend: SecretBackend::Vault,
+                    id: "0123456789abcdef".to_string(),
+                }),
+                ..sample()
+            },
+        };
+        let contents = toml::to_string_pretty(&document).unwrap();
+        assert!(contents.contains("[config.secret_ref]"));
+        std::fs::write(&path, contents).unwrap();
+
+        let imported = import(&path).unwrap();
+        assert_eq!(imported.secret_ref, None);
+        assert_eq!(imported.secret_key, None);
+    }
+
+    #[test]
+    fn rejects_newer_formats() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rustfs.toml");
+        let document = PortableConfig {
+            version: FORMAT_VERSION + 1,
+            config: sample(),
+        };
+        std::fs::write(&path, toml::to_string_pretty(&document).unwrap()).unwrap();
+        assert!(matches!(
+            import(&path),
+            Err(Error::UnsupportedConfigVersion(version)) if version == FORMAT_VERSION + 1
+        ));
+    }
+}
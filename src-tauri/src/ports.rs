@@ -0,0 +1,246 @@
+use crate::error::{Error, Result};
+use crate::state::add_app_log;
+use rustfs_launcher_shared::config::RustFsConfig;
+use serde::Serialize;
+use std::io::ErrorKind;
+use std::net::{TcpListener, ToSocketAddrs};
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PortOwner {
+    pub pid: u32,
+    pub command: String,
+}
+
+/// Fails with `Error::PortInUse` if `address` cannot be bound right now.
+pub fn check_available(address: &str) -> Result<()> {
+    let port = address
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .map(|addr| addr.port())
+        .unwrap_or_default();
+
+    match TcpListener::bind(address) {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::AddrInUse => {
+            let owner = find_owner(port);
+            add_app_log(format!(
+                "Port {} is in use (owner: {})",
+                port,
+                owner
+                    .as_ref()
+                    .map(|o| format!("PID {} {}", o.pid, o.command))
+                    .unwrap_or_else(|| "unknown".to_string())
+            ));
+            Err(Error::PortInUse { port, owner })
+        }
+        Err(e) => Err(Error::Io(e)),
+    }
+}
+
+/// Scans upward from `preferred` for a port that `host` can bind.
+///
+/// The `reserved` port (the console port, when enabled) is skipped so the two never collide.
+/// Port 0 is never returned, since binding it only asks the OS for an ephemeral port.
+pub fn find_free_port(host: &str, preferred: u16, reserved: Option<u16>) -> Result<u16> {
+    for port in preferred.max(1)..=u16::MAX {
+        if reserved == Some(port) {
+            continue;
+        }
+        if TcpListener::bind(format!("{}:{}", host, port)).is_ok() {
+            return Ok(port);
+        }
+    }
+    Err(Error::NoFreePort(preferred))
+}
+
+/// Checks the S3 endpoint and, when enabled, the console port.
+pub fn preflight(config: &RustFsConfig) -> Result<()> {
+    check_available(&config.address())?;
+    if config.console_enable {
+        check_available(&config.console_address())?;
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn find_owner(port: u16) -> Option<PortOwner> {
+    let inodes = listening_socket_inodes(port);
+    if inodes.is_empty() {
+        return None;
+    }
+
+    for entry in std::fs::read_dir("/proc").ok()?.flatten() {
+        let pid = match entry
+            .file_name()
+            .to_str()
+            .and_then(|s| s.parse::<u32>().ok())
+        {
+            Some(pid) => pid,
+            None => continue,
+        };
+        // Processes of other users are not readable; skip them quietly
+        let fds = match std::fs::read_dir(entry.path().join("fd")) {
+            Ok(fds) => fds,
+            Err(_) => continue,
+        };
+
+        for fd in fds.flatten() {
+            let target = match std::fs::read_link(fd.path()) {
+                Ok(target) => target,
+                Err(_) => continue,
+            };
+            let owns_socket = socket_inode(&target.to_string_lossy())
+                .is_some_and(|inode| inodes.iter().any(|i| i == inode));
+            if owns_socket {
+                return Some(PortOwner {
+                    pid,
+                    command: read_cmdline(pid),
+                });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+fn find_owner(_port: u16) -> Option<PortOwner> {
+    None
+}
+
+/// Collects the inodes of sockets listening on `port` from `/proc/net/tcp{,6}`.
+#[cfg(target_os = "linux")]
+fn listening_socket_inodes(port: u16) -> Vec<String> {
+    ["/proc/net/tcp", "/proc/net/tcp6"]
+        .iter()
+        .filter_map(|table| std::fs::read_to_string(table).ok())
+        .flat_map(|contents| listening_inodes(&contents, port))
+        .collect()
+}
+
+/// Parses one `/proc/net/tcp{,6}` table for the inodes of sockets listening on `port`.
+#[cfg(any(target_os = "linux", test))]
+fn listening_inodes(table: &str, port: u16) -> Vec<String> {
+    const TCP_LISTEN: &str = "0A";
+
+    let mut inodes = Vec::new();
+    for line in table.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 10 || fields[3] != TCP_LISTEN {
+            continue;
+        }
+        // The local address is `<hex ip>:<hex port>`
+        let local_port = fields[1]
+            .rsplit(':')
+            .next()
+            .and_then(|hex| u16::from_str_radix(hex, 16).ok());
+        if local_port == Some(port) {
+            inodes.push(fields[9].to_string());
+        }
+    }
+    inodes
+}
+
+/// The inode in an fd link such as `socket:[12345]`.
+#[cfg(any(target_os = "linux", test))]
+fn socket_inode(link: &str) -> Option<&str> {
+    link.strip_prefix("socket:[")
+        .and_then(|rest| rest.strip_suffix(']'))
+}
+
+#[cfg(target_os = "linux")]
+fn read_cmdline(pid: u32) -> String {
+    std::fs::read(format!("/proc/{}/cmdline", pid))
+        .map(|raw| {
+            raw.split(|b| *b == 0)
+                .filter(|part| !part.is_empty())
+                .map(|part| String::from_utf8_lossy(part).into_owned())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOST: &str = "127.0.0.1";
+
+    #[test]
+    fn skips_taken_and_reserved_ports() {
+        let taken = TcpListener::bind(format!("{}:0", HOST)).unwrap();
+        let port = taken.local_addr().unwrap().port();
+        let found = find_free_port(HOST, port, Some(port + 1)).unwrap();
+        assert!(found > port + 1);
+
+        assert!(find_free_port(HOST, 0, None).unwrap() >= 1);
+    }
+
+    const TCP: &str = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:2328 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 41001 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:2328 0100007F:D431 01 00000000:00000000 00:00000000 00000000  1000        0 41002 1 0000000000000000 20 4 30 10 -1
+   2: 00000000:2329 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 41003 1 0000000000000000 100 0 0 10 0
+";
+    const TCP6: &str = "\
+  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000000000000000000000000000:2328 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 42001 1 0000000000000000 100 0 0 10 0
+";
+
+    #[test]
+    fn finds_listening_sockets_in_proc_tables() {
+        // 0x2328 is 9000; the established connection on the same port is not a listener
+        assert_eq!(listening_inodes(TCP, 9000), ["41001"]);
+        assert_eq!(listening_inodes(TCP, 9001), ["41003"]);
+        assert!(listening_inodes(TCP, 9002).is_empty());
+        assert_eq!(listening_inodes(TCP6, 9000), ["42001"]);
+        assert!(listening_inodes("", 9000).is_empty());
+    }
+
+    #[test]
+    fn reads_socket_inodes_from_fd_links() {
+        assert_eq!(socket_inode("socket:[41001]"), Some("41001"));
+        assert_eq!(socket_inode("pipe:[41001]"), None);
+        assert_eq!(socket_inode("/dev/null"), None);
+    }
+
+    #[test]
+    fn preflight_names_the_taken_port() {
+        let taken = TcpListener::bind(format!("{}:0", HOST)).unwrap();
+        let port = taken.local_addr().unwrap().port();
+        let config = RustFsConfig {
+            host: Some(HOST.to_string()),
+            port: Some(port),
+            ..RustFsConfig::default()
+        };
+        match preflight(&config) {
+            Err(Error::PortInUse {
+                port: reported,
+                owner,
+            }) => {
+                assert_eq!(reported, port);
+                #[cfg(target_os = "linux")]
+                assert_eq!(owner.map(|owner| owner.pid), Some(std::process::id()));
+                #[cfg(not(target_os = "linux"))]
+                let _ = owner;
+            }
+            other => panic!("expected PortInUse, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn fails_when_every_port_is_taken() {
+        // Held if it was free; otherwise something else already holds it
+        let last = TcpListener::bind(format!("{}:{}", HOST, u16::MAX));
+        assert!(matches!(
+            find_free_port(HOST, u16::MAX, None),
+            Err(Error::NoFreePort(u16::MAX))
+        ));
+        drop(last);
+        assert!(matches!(
+            find_free_port(HOST, u16::MAX, Some(u16::MAX)),
+            Err(Error::NoFreePort(u16::MAX))
+        ));
+    }
+}
@@ -0,0 +1,69 @@
+use std::io::{Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+const HEALTH_PATH: &str = "/health";
+
+/// Resolves `address`, mapping wildcard binds to the matching loopback address.
+fn resolve(address: &str) -> Result<SocketAddr, String> {
+    let mut addr = address
+        .to_socket_addrs()
+        .map_err(|e| format!("cannot resolve {}: {}", address, e))?
+        .next()
+        .ok_or_else(|| format!("{} did not resolve to any address", address))?;
+
+    if addr.ip().is_unspecified() {
+        let loopback = match addr.ip() {
+            IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+        };
+        addr.set_ip(loopback);
+    }
+    Ok(addr)
+}
+
+/// Checks that something accepts TCP connections on `address`.
+pub fn tcp(address: &str, timeout: Duration) -> Result<Duration, String> {
+    let addr = resolve(address)?;
+    let started = Instant::now();
+    TcpStream::connect_timeout(&addr, timeout).map_err(|e| e.to_string())?;
+    Ok(started.elapsed())
+}
+
+/// Probes the S3 endpoint: over HTTP normally, or a TCP connect when RustFS serves TLS.
+pub fn endpoint(address: &str, tls: bool, timeout: Duration) -> Result<Duration, String> {
+    if tls {
+        tcp(address, timeout)
+    } else {
+        http(address, timeout)
+    }
+}
+
+/// Sends `GET /health` to `address` and returns the latency of the first response line.
+///
+/// Any HTTP status counts as serving; only connection and protocol failures are errors.
+pub fn http(address: &str, timeout: Duration) -> Result<Duration, String> {
+    let addr = resolve(address)?;
+    let started = Instant::now();
+    let mut stream = TcpStream::connect_timeout(&addr, timeout).map_err(|e| e.to_string())?;
+    stream
+        .set_read_timeout(Some(timeout))
+        .and_then(|_| stream.set_write_timeout(Some(timeout)))
+        .map_err(|e| e.to_string())?;
+
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+        HEALTH_PATH, addr
+    );
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    let mut buffer = [0u8; 16];
+    let read = stream.read(&mut buffer).map_err(|e| e.to_string())?;
+    if buffer[..read].starts_with(b"HTTP/") {
+        Ok(started.elapsed())
+    } else {
+        Err("unexpected response to health request".to_string())
+    }
+}
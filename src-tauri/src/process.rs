@@ -1,10 +1,39 @@
-use crate::config::RustFsConfig;
 use crate::error::{Error, Result};
-use crate::state::{add_app_log, add_rustfs_log, set_rustfs_process};
+use crate::ports;
+use crate::probe;
+use crate::secrets;
+use crate::state::{
+    add_app_log, add_rustfs_log, get_active_config, get_rustfs_status, has_rustfs_process,
+    recent_rustfs_stderr, release_rustfs_slot, reserve_rustfs_slot, running_rustfs_pid,
+    rustfs_slot_busy, set_active_config, set_rustfs_process, set_rustfs_status,
+    set_shutdown_timeout, terminate_rustfs_process, ProcessSlot, RUSTFS_PROCESS,
+};
+use crate::status;
+use crate::supervisor;
+use crate::validate;
+use crate::volumes;
+use rustfs_launcher_shared::config::{CredentialDelivery, RustFsConfig};
+use rustfs_launcher_shared::status::RustFsStatus;
 use std::io::{BufRead, BufReader};
+use std::net::TcpListener;
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
 use std::thread;
+use std::time::{Duration, Instant};
+
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const PORT_RELEASE_TIMEOUT: Duration = Duration::from_secs(10);
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const READY_PROBE_TIMEOUT: Duration = Duration::from_secs(1);
+const READY_STDERR_LINES: usize = 10;
+
+pub const ACCESS_KEY_ENV: &str = "RUSTFS_ACCESS_KEY";
+pub const SECRET_KEY_ENV: &str = "RUSTFS_SECRET_KEY";
+
+#[cfg(unix)]
+const GRACEFUL_SIGNAL: &str = "SIGTERM";
+#[cfg(windows)]
+const GRACEFUL_SIGNAL: &str = "CTRL_BREAK";
 
 fn inferred_binary_name() -> &'static str {
     use std::env::consts::{ARCH, OS};
@@ -159,66 +188,141 @@ pub fn diagnose_binary() -> Result<String> {
     }
 }
 
-pub fn launch(config: RustFsConfig) -> Result<String> {
+pub struct Launched {
+    pub pid: u32,
+    pub address: String,
+}
+
+pub fn launch(mut config: RustFsConfig) -> Result<Launched> {
     add_app_log("Launch command received".to_string());
     add_app_log(format!(
-        "Config: data_path={}, port={:?}, host={:?}",
-        config.data_path, config.port, config.host
+        "Config: data_volumes={:?}, port={:?}, host={:?}",
+        config.data_volumes, config.port, config.host
     ));
 
-    if config.data_path.is_empty() {
-        return Err(Error::DataPathRequired);
+    validate::check(&config)?;
+
+    // Held from here until the child is registered, so concurrent launches cannot both pass
+    let token = reserve_rustfs_slot().inspect_err(|err| add_app_log(err.to_string()))?;
+    let result = prepare_and_spawn(&mut config, token);
+    release_rustfs_slot(token);
+    let pid = result?;
+
+    let address = config.address();
+    supervisor::start(config, pid);
+
+    Ok(Launched { pid, address })
+}
+
+fn prepare_and_spawn(config: &mut RustFsConfig, token: u64) -> Result<u32> {
+    if config.auto_port {
+        let host = config.host.as_deref().unwrap_or("127.0.0.1");
+        let port = ports::find_free_port(
+            host,
+            config.port.unwrap_or(9000),
+            config.console_enable.then(|| config.console_port()),
+        )?;
+        add_app_log(format!("Auto-selected free port: {}", port));
+        config.port = Some(port);
+    }
+    ports::preflight(config)?;
+
+    spawn_reserved(config, token)
+}
+
+/// Builds the RustFS command line and environment for `config`.
+fn build_command(binary_path: &Path, config: &RustFsConfig, logs_dir: &Path) -> Command {
+    let mut cmd = Command::new(binary_path);
+    cmd.env(
+        "RUSTFS_OBS_LOG_DIRECTORY",
+        logs_dir.to_string_lossy().to_string(),
+    );
+    cmd.args(
+        config
+            .data_volumes
+            .iter()
+            .map(|volume| volume.trim())
+            .filter(|volume| !volume.is_empty()),
+    );
+
+    cmd.arg("--address").arg(config.address());
+
+    let credentials = [
+        ("--access-key", ACCESS_KEY_ENV, &config.access_key),
+        ("--secret-key", SECRET_KEY_ENV, &config.secret_key),
+    ];
+    for (flag, var, value) in credentials {
+        let Some(value) = value else { continue };
+        match config.credential_delivery {
+            CredentialDelivery::Env => cmd.env(var, value),
+            CredentialDelivery::Argv => cmd.arg(flag).arg(value),
+        };
+    }
+    if config.console_enable {
+        cmd.arg("--console-enable");
+        // Always explicit, so the port probed for readiness and health is the one RustFS binds
+        cmd.arg("--console-address").arg(config.console_address());
+    }
+    if let Some(tls_path) = &config.tls_path {
+        cmd.arg("--tls-path").arg(tls_path);
+    }
+    if let Some(region) = &config.region {
+        cmd.arg("--region").arg(region);
     }
+    let server_domains = config.server_domains();
+    if !server_domains.is_empty() {
+        cmd.arg("--server-domains").arg(server_domains.join(","));
+    }
+    if let Some(obs_endpoint) = &config.obs_endpoint {
+        cmd.arg("--obs-endpoint").arg(obs_endpoint);
+    }
+    cmd.args(&config.extra_args);
+    cmd.envs(&config.env);
+    cmd
+}
+
+/// Spawns a RustFS child for `config` and registers it as the tracked process.
+pub fn spawn(config: &RustFsConfig) -> Result<u32> {
+    let token = reserve_rustfs_slot()?;
+    let result = spawn_reserved(config, token);
+    release_rustfs_slot(token);
+    result
+}
 
+fn spawn_reserved(config: &RustFsConfig, token: u64) -> Result<u32> {
     let binary_path = match &config.binary_path {
         Some(path) => PathBuf::from(path),
         None => get_binary_path()?,
     };
     check_permissions(&binary_path)?;
 
-    // Create logs directory parallel to data_path
-    let data_path = Path::new(&config.data_path);
-    let logs_dir = if let Some(parent) = data_path.parent() {
-        parent.join("logs")
-    } else {
-        Path::new("logs").to_path_buf()
-    };
+    let logs_dir = volumes::logs_dir(&config.data_volumes);
     add_app_log(format!(
         "Creating logs directory at: {}",
         logs_dir.display()
     ));
     std::fs::create_dir_all(&logs_dir).map_err(Error::Io)?;
 
-    let mut cmd = Command::new(&binary_path);
-    cmd.env(
-        "RUSTFS_OBS_LOG_DIRECTORY",
-        logs_dir.to_string_lossy().to_string(),
-    );
-    cmd.arg(&config.data_path);
-
-    let address = format!(
-        "{}:{}",
-        config.host.as_deref().unwrap_or("127.0.0.1"),
-        config.port.unwrap_or(9000)
-    );
-    cmd.arg("--address").arg(&address);
+    let resolved = secrets::resolve(config)?;
+    let mut cmd = build_command(&binary_path, &resolved, &logs_dir);
 
-    if let Some(access_key) = &config.access_key {
-        cmd.arg("--access-key").arg(access_key);
-    }
-    if let Some(secret_key) = &config.secret_key {
-        cmd.arg("--secret-key").arg(secret_key);
-    }
-    if config.console_enable {
-        cmd.arg("--console-enable");
+    // CTRL_BREAK can only be delivered to a process that owns its own console group
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        use windows_sys::Win32::System::Threading::CREATE_NEW_PROCESS_GROUP;
+        cmd.creation_flags(CREATE_NEW_PROCESS_GROUP);
     }
 
     add_app_log(format!("Spawning command: {:?}", cmd));
-    let mut child = cmd
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(Error::BinaryExecution)?;
+    set_rustfs_status(RustFsStatus::Starting);
+    let mut child = match cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            set_rustfs_status(RustFsStatus::Stopped);
+            return Err(Error::BinaryExecution(e));
+        }
+    };
 
     let pid = child.id();
     add_app_log(format!("RustFS launched successfully with PID: {}", pid));
@@ -249,7 +353,340 @@ pub fn launch(config: RustFsConfig) -> Result<String> {
     }
 
     // Register the process for tracking before spawning the wait thread
-    set_rustfs_process(child);
+    set_shutdown_timeout(Duration::from_secs(config.shutdown_timeout_secs));
+    if let Err(mut child) = set_rustfs_process(token, child) {
+        // Never leave a child running that nothing tracks
+        let _ = child.kill();
+        let _ = child.wait();
+        set_rustfs_status(RustFsStatus::Stopped);
+        return Err(Error::ProcessBusy);
+    }
+    set_active_config(config.clone());
+
+    wait_until_ready(config, pid)?;
+    set_rustfs_status(RustFsStatus::Running { pid });
+
+    Ok(pid)
+}
+
+/// Polls the endpoint until RustFS answers, the process exits, or the timeout passes.
+fn wait_until_ready(config: &RustFsConfig, pid: u32) -> Result<()> {
+    let address = config.address();
+    let timeout = Duration::from_secs(config.ready_timeout_secs);
+    add_app_log(format!(
+        "Waiting up to {}s for RustFS to serve on {}",
+        timeout.as_secs(),
+        address
+    ));
+
+    let started = Instant::now();
+    loop {
+        if let Some(status) = take_exited_process(pid) {
+            // Give the output readers a moment to flush the final stderr lines
+            thread::sleep(READY_POLL_INTERVAL);
+            set_rustfs_status(status::from_exit_status(status));
+            return Err(Error::NotReady {
+                reason: format!("process exited during startup ({})", status),
+                stderr: recent_rustfs_stderr(READY_STDERR_LINES),
+            });
+        }
+
+        if running_rustfs_pid() != Some(pid) {
+            return Err(Error::NotReady {
+                reason: "process was stopped during startup".to_string(),
+                stderr: Vec::new(),
+            });
+        }
+
+        let last_error =
+            match probe::endpoint(&address, config.tls_path.is_some(), READY_PROBE_TIMEOUT) {
+                Ok(latency) => {
+                    add_app_log(format!(
+                        "RustFS is ready on {} after {:.1}s (probe latency {}ms)",
+                        address,
+                        started.elapsed().as_secs_f64(),
+                        latency.as_millis()
+                    ));
+                    return Ok(());
+                }
+                Err(e) => e,
+            };
+
+        if started.elapsed() >= timeout {
+            add_app_log(format!(
+                "RustFS did not become ready within {}s, stopping it",
+                timeout.as_secs()
+            ));
+            let status = terminate_rustfs_process();
+            set_rustfs_status(RustFsStatus::Crashed {
+                code: status.and_then(|s| s.code()),
+                signal: None,
+            });
+            return Err(Error::NotReady {
+                reason: format!(
+                    "no response from {} within {}s (last error: {})",
+                    address,
+                    timeout.as_secs(),
+                    last_error
+                ),
+                stderr: recent_rustfs_stderr(READY_STDERR_LINES),
+            });
+        }
+        thread::sleep(READY_POLL_INTERVAL);
+    }
+}
+
+fn take_exited_process(pid: u32) -> Option<ExitStatus> {
+    let mut slot = RUSTFS_PROCESS.lock().unwrap();
+    match slot.child(pid)?.try_wait() {
+        Ok(Some(status)) => {
+            *slot = ProcessSlot::Empty;
+            Some(status)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(unix)]
+fn request_graceful_exit(child: &Child) -> std::io::Result<()> {
+    // SAFETY: `kill` only sends a signal to the PID of a child we still own.
+    let result = unsafe { libc::kill(child.id() as libc::pid_t, libc::SIGTERM) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(windows)]
+fn request_graceful_exit(child: &Child) -> std::io::Result<()> {
+    use windows_sys::Win32::System::Console::{
+        AttachConsole, FreeConsole, GenerateConsoleCtrlEvent, CTRL_BREAK_EVENT,
+    };
+    let pid = child.id();
+    // Console events only reach processes attached to the caller's console. Debug builds
+    // share theirs with the child, so the event can be sent directly.
+    // SAFETY: the child was spawned with CREATE_NEW_PROCESS_GROUP, so its PID is a group ID.
+    if unsafe { GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, pid) } != 0 {
+        return Ok(());
+    }
+
+    // Release builds use the windows subsystem and have no console, so borrow the child's
+    // for as long as it takes to send the event.
+    // SAFETY: the console is detached again before returning.
+    unsafe {
+        if AttachConsole(pid) == 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        let result = GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, pid);
+        let error = std::io::Error::last_os_error();
+        FreeConsole();
+        if result != 0 {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+}
+
+/// Asks RustFS to exit, waits up to `timeout`, then falls back to a hard kill.
+pub fn shutdown(mut child: Child, timeout: Duration) -> Option<ExitStatus> {
+    let pid = child.id();
 
-    Ok(format!("RustFS launched with PID: {}", pid))
+    match request_graceful_exit(&child) {
+        Ok(()) => {
+            add_app_log(format!(
+                "Sent {} to RustFS (PID: {}), waiting up to {}s for it to exit",
+                GRACEFUL_SIGNAL,
+                pid,
+                timeout.as_secs()
+            ));
+            add_rustfs_log(format!("Shutdown requested ({})", GRACEFUL_SIGNAL));
+
+            let deadline = Instant::now() + timeout;
+            loop {
+                match child.try_wait() {
+                    Ok(Some(status)) => {
+                        add_app_log(format!("RustFS exited gracefully ({})", status));
+                        return Some(status);
+                    }
+                    Ok(None) if Instant::now() >= deadline => {
+                        add_app_log(format!(
+                            "RustFS did not exit within {}s, escalating to hard kill",
+                            timeout.as_secs()
+                        ));
+                        break;
+                    }
+                    Ok(None) => thread::sleep(SHUTDOWN_POLL_INTERVAL),
+                    Err(e) => {
+                        add_app_log(format!("Failed to poll RustFS process: {}", e));
+                        break;
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            add_app_log(format!(
+                "Failed to send {} to RustFS (PID: {}): {}, falling back to hard kill",
+                GRACEFUL_SIGNAL, pid, e
+            ));
+        }
+    }
+
+    match child.kill() {
+        Ok(_) => {
+            let status = child.wait().ok();
+            add_app_log(format!(
+                "RustFS process killed ({})",
+                status
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "exit status unavailable".to_string())
+            ));
+            status
+        }
+        Err(e) => {
+            // The process may have exited between the last poll and the kill
+            add_app_log(format!("Failed to kill RustFS process: {}", e));
+            child.try_wait().ok().flatten()
+        }
+    }
+}
+
+pub fn stop() -> Result<String> {
+    add_app_log("Stop command received".to_string());
+    if rustfs_slot_busy() {
+        return Err(Error::ProcessBusy);
+    }
+    supervisor::cancel();
+    if !has_rustfs_process() {
+        if let RustFsStatus::Restarting { .. } = get_rustfs_status() {
+            set_rustfs_status(RustFsStatus::Stopped);
+            return Ok("Pending RustFS restart cancelled".to_string());
+        }
+        return Err(Error::NotRunning);
+    }
+
+    let status = terminate_rustfs_process();
+    let message = match status {
+        Some(status) => format!("RustFS stopped ({})", status),
+        None => "RustFS stopped (exit status unavailable)".to_string(),
+    };
+    add_rustfs_log("RustFS process stopped".to_string());
+    // A requested stop is a clean exit even if the process died from the signal
+    set_rustfs_status(RustFsStatus::Exited {
+        code: status.and_then(|s| s.code()),
+    });
+    Ok(message)
+}
+
+/// Stops the current instance, waits for its port, then launches with `config`.
+pub fn restart(config: RustFsConfig) -> Result<Launched> {
+    add_app_log("Restart command received".to_string());
+    // Tearing down underneath an unfinished launch would orphan the child it is spawning
+    if rustfs_slot_busy() {
+        return Err(Error::ProcessBusy);
+    }
+    supervisor::cancel();
+
+    let previous = get_active_config();
+    if has_rustfs_process() {
+        let status = terminate_rustfs_process();
+        add_app_log(format!(
+            "Previous RustFS instance stopped ({})",
+            status
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "exit status unavailable".to_string())
+        ));
+    }
+    if let Some(previous) = previous {
+        wait_for_port_release(&previous.address())?;
+        if previous.console_enable {
+            wait_for_port_release(&previous.console_address())?;
+        }
+    }
+
+    add_rustfs_log("--- restarted ---".to_string());
+    launch(config)
+}
+
+fn wait_for_port_release(address: &str) -> Result<()> {
+    add_app_log(format!("Waiting for {} to be released", address));
+    let deadline = Instant::now() + PORT_RELEASE_TIMEOUT;
+    loop {
+        if TcpListener::bind(address).is_ok() {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            return Err(Error::PortNotReleased(address.to_string()));
+        }
+        thread::sleep(SHUTDOWN_POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsStr;
+
+    fn command(delivery: CredentialDelivery) -> Command {
+        let config = RustFsConfig {
+            data_volumes: vec!["/data/vol1".to_string()],
+            access_key: Some("launcher".to_string()),
+            secret_key: Some("launcher-secret".to_string()),
+            credential_delivery: delivery,
+            ..RustFsConfig::default()
+        };
+        build_command(Path::new("rustfs"), &config, Path::new("/data/logs"))
+    }
+
+    fn env<'a>(cmd: &'a Command, name: &str) -> Option<&'a OsStr> {
+        cmd.get_envs()
+            .find(|(key, _)| *key == name)
+            .and_then(|(_, value)| value)
+    }
+
+    #[test]
+    fn env_delivery_keeps_secrets_out_of_argv() {
+        let cmd = command(CredentialDelivery::Env);
+        for arg in cmd.get_args() {
+            assert_ne!(arg, "launcher");
+            assert_ne!(arg, "launcher-secret");
+            assert_ne!(arg, "--access-key");
+            assert_ne!(arg, "--secret-key");
+        }
+        assert_eq!(env(&cmd, ACCESS_KEY_ENV), Some(OsStr::new("launcher")));
+        assert_eq!(
+            env(&cmd, SECRET_KEY_ENV),
+            Some(OsStr::new("launcher-secret"))
+        );
+    }
+
+    #[test]
+    fn argv_delivery_passes_flags() {
+        let cmd = command(CredentialDelivery::Argv);
+        let args: Vec<_> = cmd.get_args().collect();
+        assert!(args
+            .windows(2)
+            .any(|pair| pair == ["--secret-key", "launcher-secret"]));
+        assert_eq!(env(&cmd, SECRET_KEY_ENV), None);
+    }
+
+    #[test]
+    fn console_address_is_always_passed() {
+        let mut config = RustFsConfig {
+            data_volumes: vec!["/data/vol1".to_string()],
+            host: Some("0.0.0.0".to_string()),
+            console_enable: true,
+            ..RustFsConfig::default()
+        };
+        let cmd = build_command(Path::new("rustfs"), &config, Path::new("/data/logs"));
+        let args: Vec<_> = cmd.get_args().collect();
+        assert!(args
+            .windows(2)
+            .any(|pair| pair == ["--console-address", "0.0.0.0:9001"]));
+
+        config.console_enable = false;
+        let cmd = build_command(Path::new("rustfs"), &config, Path::new("/data/logs"));
+        assert!(!cmd.get_args().any(|arg| arg == "--console-address"));
+    }
 }
@@ -0,0 +1,206 @@
+//! Keeps secret keys out of saved configs.
+//!
+//! Saved profiles hold a [`SecretRef`] instead of the key itself; the key is read back
+//! from its backend only when RustFS is spawned or a config is exported with secrets.
+
+use crate::error::{Error, Result};
+use crate::state::add_app_log;
+use crate::vault::VaultStore;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::OsRng;
+use rustfs_launcher_shared::config::{RustFsConfig, SecretBackend, SecretRef, DEFAULT_CREDENTIAL};
+
+const KEYRING_SERVICE: &str = "rustfs-launcher";
+
+/// A backend secrets can be saved to and read back from by id.
+pub trait SecretStore {
+    fn get(&self, id: &str) -> Result<String>;
+    fn set(&self, id: &str, secret: &str) -> Result<()>;
+    /// Removes a secret; removing one that does not exist is not an error.
+    fn delete(&self, id: &str) -> Result<()>;
+}
+
+/// The platform credential store.
+#[cfg_attr(test, allow(dead_code))]
+pub struct KeyringStore;
+
+impl KeyringStore {
+    fn entry(id: &str) -> Result<keyring::Entry> {
+        Ok(keyring::Entry::new(KEYRING_SERVICE, id)?)
+    }
+}
+
+impl SecretStore for KeyringStore {
+    fn get(&self, id: &str) -> Result<String> {
+        match Self::entry(id)?.get_password() {
+            Ok(secret) => Ok(secret),
+            Err(keyring::Error::NoEntry) => Err(Error::SecretNotFound(id.to_string())),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn set(&self, id: &str, secret: &str) -> Result<()> {
+        Ok(Self::entry(id)?.set_password(secret)?)
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        match Self::entry(id)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// Stands in for the keyring in unit tests, which must never touch the real one.
+#[cfg(test)]
+struct MemoryStore;
+
+#[cfg(test)]
+static MEMORY: std::sync::Mutex<std::collections::BTreeMap<String, String>> =
+    std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+#[cfg(test)]
+impl SecretStore for MemoryStore {
+    fn get(&self, id: &str) -> Result<String> {
+        MEMORY
+            .lock()
+            .unwrap()
+            .get(id)
+            .cloned()
+            .ok_or_else(|| Error::SecretNotFound(id.to_string()))
+    }
+
+    fn set(&self, id: &str, secret: &str) -> Result<()> {
+        MEMORY
+            .lock()
+            .unwrap()
+            .insert(id.to_string(), secret.to_string());
+        Ok(())
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        MEMORY.lock().unwrap().remove(id);
+        Ok(())
+    }
+}
+
+fn store_for(backend: SecretBackend) -> Box<dyn SecretStore> {
+    match backend {
+        #[cfg(not(test))]
+        SecretBackend::Keyring => Box::new(KeyringStore),
+        #[cfg(test)]
+        SecretBackend::Keyring => Box::new(MemoryStore),
+        SecretBackend::Vault => Box::new(VaultStore),
+    }
+}
+
+fn get(secret_ref: &SecretRef) -> Result<String> {
+    store_for(secret_ref.backend).get(&secret_ref.id)
+}
+
+fn new_id() -> String {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes);
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Moves a typed-in secret key into `config.secret_backend` before the config is saved.
+///
+/// `previous` is the reference already saved for this profile; it is reused so repeated
+/// saves overwrite one entry instead of leaving stale ones behind.
+pub fn seal(config: &mut RustFsConfig, previous: Option<SecretRef>) -> Result<()> {
+    let current = config.secret_ref.take().or(previous);
+    if config.secret_key.as_deref() == Some(DEFAULT_CREDENTIAL) {
+        // The default key is public, so there is nothing to protect
+        if let Some(old) = &current {
+            forget(old);
+        }
+        return Ok(());
+    }
+
+    let secret = match config.secret_key.take() {
+        Some(secret) => secret,
+        // Move a saved key when the preferred backend changed
+        None => match &current {
+            Some(old) if old.backend != config.secret_backend => get(old)?,
+            _ => {
+                config.secret_ref = current;
+                return Ok(());
+            }
+        },
+    };
+    let secret_ref = match &current {
+        Some(old) if old.backend == config.secret_backend => old.clone(),
+        _ => SecretRef {
+            backend: config.secret_backend,
+            id: new_id(),
+        },
+    };
+    store_for(secret_ref.backend).set(&secret_ref.id, &secret)?;
+    if let Some(old) = current.filter(|old| *old != secret_ref) {
+        forget(&old);
+    }
+    config.secret_ref = Some(secret_ref);
+    Ok(())
+}
+
+/// Saves `config`'s secret under a new reference, so copied profiles never share an entry.
+pub fn detach(config: &mut RustFsConfig) -> Result<()> {
+    if let Some(secret_ref) = config.secret_ref.take() {
+        if config.secret_key.is_none() {
+            config.secret_key = Some(get(&secret_ref)?);
+        }
+    }
+    seal(config, None)
+}
+
+/// Returns a copy of `config` with the saved secret key filled in.
+pub fn resolve(config: &RustFsConfig) -> Result<RustFsConfig> {
+    let mut resolved = config.clone();
+    if let Some(secret_ref) = resolved.secret_ref.take() {
+        if resolved.secret_key.is_none() {
+            resolved.secret_key = Some(get(&secret_ref)?);
+        }
+    }
+    Ok(resolved)
+}
+
+/// Removes a saved secret that nothing refers to any more.
+pub fn forget(secret_ref: &SecretRef) {
+    if let Err(err) = store_for(secret_ref.backend).delete(&secret_ref.id) {
+        add_app_log(format!(
+            "Failed to remove saved secret {}: {}",
+            secret_ref.id, err
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sealing_reuses_the_saved_reference() {
+        let mut config = RustFsConfig {
+            secret_key: Some("launcher-secret".to_string()),
+            ..RustFsConfig::default()
+        };
+        seal(&mut config, None).unwrap();
+        let secret_ref = config.secret_ref.clone().unwrap();
+        assert_eq!(config.secret_key, None);
+        assert_eq!(
+            resolve(&config).unwrap().secret_key.as_deref(),
+            Some("launcher-secret")
+        );
+
+        config.secret_key = Some("rotated-secret".to_string());
+        seal(&mut config, None).unwrap();
+        assert_eq!(config.secret_ref.as_ref(), Some(&secret_ref));
+        assert_eq!(get(&secret_ref).unwrap(), "rotated-secret");
+
+        config.secret_key = Some(DEFAULT_CREDENTIAL.to_string());
+        seal(&mut config, None).unwrap();
+        assert_eq!(config.secret_ref, None);
+        assert!(matches!(get(&secret_ref), Err(Error::SecretNotFound(_))));
+    }
+}
@@ -1,16 +1,53 @@
+use crate::error::{Error, Result};
 use lazy_static::lazy_static;
 use regex::Regex;
+use rustfs_launcher_shared::config::RustFsConfig;
+use rustfs_launcher_shared::events;
+use rustfs_launcher_shared::log::LogEntry;
+use rustfs_launcher_shared::redact::redact;
+use rustfs_launcher_shared::status::RustFsStatus;
+use serde::Serialize;
 use std::collections::VecDeque;
-use std::process::Child;
+use std::process::{Child, ExitStatus};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager};
 
 lazy_static! {
-    pub static ref APP_LOGS: Arc<Mutex<VecDeque<String>>> = Arc::new(Mutex::new(VecDeque::new()));
-    pub static ref RUSTFS_LOGS: Arc<Mutex<VecDeque<String>>> =
+    pub static ref APP_LOGS: Arc<Mutex<VecDeque<LogEntry>>> = Arc::new(Mutex::new(VecDeque::new()));
+    pub static ref RUSTFS_LOGS: Arc<Mutex<VecDeque<LogEntry>>> =
         Arc::new(Mutex::new(VecDeque::new()));
     pub static ref APP_HANDLE: Arc<Mutex<Option<AppHandle>>> = Arc::new(Mutex::new(None));
-    pub static ref RUSTFS_PROCESS: Arc<Mutex<Option<Child>>> = Arc::new(Mutex::new(None));
+    pub static ref RUSTFS_PROCESS: Arc<Mutex<ProcessSlot>> =
+        Arc::new(Mutex::new(ProcessSlot::Empty));
+    pub static ref ACTIVE_CONFIG: Arc<Mutex<Option<RustFsConfig>>> = Arc::new(Mutex::new(None));
+    pub static ref RUSTFS_STATUS: Arc<Mutex<RustFsStatus>> =
+        Arc::new(Mutex::new(RustFsStatus::Stopped));
+    pub static ref SHUTDOWN_TIMEOUT: Arc<Mutex<Duration>> =
+        Arc::new(Mutex::new(Duration::from_secs(10)));
+}
+
+static NEXT_RESERVATION: AtomicU64 = AtomicU64::new(1);
+
+/// The single RustFS process the launcher manages.
+pub enum ProcessSlot {
+    Empty,
+    /// Claimed by a launch that has not registered its child yet.
+    Starting(u64),
+    Running(Child),
+    /// Taken out for shutdown; holds the PID until the process has exited.
+    Stopping(u32),
+}
+
+impl ProcessSlot {
+    /// The tracked child, if it matches `pid`.
+    pub fn child(&mut self, pid: u32) -> Option<&mut Child> {
+        match self {
+            ProcessSlot::Running(child) if child.id() == pid => Some(child),
+            _ => None,
+        }
+    }
 }
 
 lazy_static! {
@@ -21,13 +58,11 @@ fn clean_ansi_codes(s: &str) -> String {
     ANSI_REGEX.replace_all(s, "").to_string()
 }
 
-fn buffer_log(logs: &Arc<Mutex<VecDeque<String>>>, message: String, capacity: usize) -> String {
-    let cleaned_message = clean_ansi_codes(&message);
-    let log_entry = format!(
-        "[{}] {}",
-        chrono::Local::now().format("%H:%M:%S"),
-        cleaned_message
-    );
+fn buffer_log(logs: &Arc<Mutex<VecDeque<LogEntry>>>, message: String, capacity: usize) -> LogEntry {
+    let log_entry = LogEntry {
+        time: chrono::Local::now().format("%H:%M:%S").to_string(),
+        message: redact(&clean_ansi_codes(&message)),
+    };
 
     {
         let mut logs = logs.lock().unwrap();
@@ -40,64 +75,196 @@ fn buffer_log(logs: &Arc<Mutex<VecDeque<String>>>, message: String, capacity: us
     log_entry
 }
 
-fn emit_log(event_name: &str, log_entry: String) {
+pub fn emit_event<T: Serialize + Clone>(event_name: &str, payload: T) {
     if let Some(handle) = APP_HANDLE.lock().unwrap().as_ref() {
         if let Some(window) = handle.get_webview_window("main") {
-            let _ = window.emit(event_name, log_entry);
+            let _ = window.emit(event_name, payload);
         }
     }
 }
 
-const APP_LOG_EVENT: &str = "app-log";
-const RUSTFS_LOG_EVENT: &str = "rustfs-log";
 const APP_LOG_CAPACITY: usize = 100;
 const RUSTFS_LOG_CAPACITY: usize = 1000;
 
 pub fn add_app_log(message: String) {
     let entry = buffer_log(&APP_LOGS, message, APP_LOG_CAPACITY);
-    emit_log(APP_LOG_EVENT, entry);
+    emit_event(events::APP_LOG, entry);
 }
 
 pub fn add_rustfs_log(message: String) {
     let entry = buffer_log(&RUSTFS_LOGS, message, RUSTFS_LOG_CAPACITY);
-    emit_log(RUSTFS_LOG_EVENT, entry);
+    emit_event(events::RUSTFS_LOG, entry);
 }
 
 pub fn set_app_handle(handle: AppHandle) {
     *APP_HANDLE.lock().unwrap() = Some(handle);
 }
 
-pub fn get_app_logs() -> Vec<String> {
+pub fn get_app_logs() -> Vec<LogEntry> {
     APP_LOGS.lock().unwrap().iter().cloned().collect()
 }
 
-pub fn get_rustfs_logs() -> Vec<String> {
+pub fn get_rustfs_logs() -> Vec<LogEntry> {
     RUSTFS_LOGS.lock().unwrap().iter().cloned().collect()
 }
 
-pub fn set_rustfs_process(process: Child) {
+/// Returns up to `limit` of the most recent stderr lines captured from RustFS.
+pub fn recent_rustfs_stderr(limit: usize) -> Vec<String> {
+    let logs = RUSTFS_LOGS.lock().unwrap();
+    let mut lines: Vec<String> = logs
+        .iter()
+        .rev()
+        .filter(|entry| entry.message.contains("[STDERR] "))
+        .take(limit)
+        .map(LogEntry::to_string)
+        .collect();
+    lines.reverse();
+    lines
+}
+
+/// Claims the empty process slot for a launch, returning the token to register its child with.
+pub fn reserve_rustfs_slot() -> Result<u64> {
+    let mut slot = RUSTFS_PROCESS.lock().unwrap();
+    match &mut *slot {
+        ProcessSlot::Running(child) => {
+            // An exited child nobody has collected yet frees the slot
+            if let Ok(None) = child.try_wait() {
+                return Err(Error::AlreadyRunning { pid: child.id() });
+            }
+        }
+        ProcessSlot::Starting(_) | ProcessSlot::Stopping(_) => return Err(Error::ProcessBusy),
+        ProcessSlot::Empty => {}
+    }
+    let token = NEXT_RESERVATION.fetch_add(1, Ordering::SeqCst);
+    *slot = ProcessSlot::Starting(token);
+    Ok(token)
+}
+
+/// Gives up a reservation whose launch failed; a no-op once its child is registered.
+pub fn release_rustfs_slot(token: u64) {
+    let mut slot = RUSTFS_PROCESS.lock().unwrap();
+    if matches!(*slot, ProcessSlot::Starting(reserved) if reserved == token) {
+        *slot = ProcessSlot::Empty;
+    }
+}
+
+/// Registers the child of the launch holding `token`, handing it back if the slot was lost.
+pub fn set_rustfs_process(token: u64, process: Child) -> std::result::Result<(), Child> {
     let pid = process.id();
-    *RUSTFS_PROCESS.lock().unwrap() = Some(process);
+    {
+        let mut slot = RUSTFS_PROCESS.lock().unwrap();
+        if !matches!(*slot, ProcessSlot::Starting(reserved) if reserved == token) {
+            return Err(process);
+        }
+        *slot = ProcessSlot::Running(process);
+    }
     add_app_log(format!("RustFS process registered with PID: {}", pid));
+    Ok(())
 }
 
-pub fn terminate_rustfs_process() {
-    let mut process_guard = RUSTFS_PROCESS.lock().unwrap();
-    if let Some(mut process) = process_guard.take() {
-        let pid = process.id();
-        add_app_log(format!("Terminating RustFS process with PID: {}", pid));
+/// Returns the PID of the tracked process if it has not exited yet.
+pub fn running_rustfs_pid() -> Option<u32> {
+    let mut slot = RUSTFS_PROCESS.lock().unwrap();
+    let ProcessSlot::Running(child) = &mut *slot else {
+        return None;
+    };
+    match child.try_wait() {
+        Ok(None) => Some(child.id()),
+        _ => None,
+    }
+}
 
-        match process.kill() {
-            Ok(_) => {
-                add_app_log("RustFS process terminated successfully".to_string());
-                // Wait for the process to actually exit
-                let _ = process.wait();
+/// Whether a child is registered, even one that has exited but not been collected.
+pub fn has_rustfs_process() -> bool {
+    matches!(*RUSTFS_PROCESS.lock().unwrap(), ProcessSlot::Running(_))
+}
+
+/// Whether a launch or shutdown is in progress.
+pub fn rustfs_slot_busy() -> bool {
+    matches!(
+        *RUSTFS_PROCESS.lock().unwrap(),
+        ProcessSlot::Starting(_) | ProcessSlot::Stopping(_)
+    )
+}
+
+pub fn set_active_config(config: RustFsConfig) {
+    *ACTIVE_CONFIG.lock().unwrap() = Some(config);
+}
+
+pub fn get_active_config() -> Option<RustFsConfig> {
+    ACTIVE_CONFIG.lock().unwrap().clone()
+}
+
+pub fn get_rustfs_status() -> RustFsStatus {
+    RUSTFS_STATUS.lock().unwrap().clone()
+}
+
+pub fn set_rustfs_status(status: RustFsStatus) {
+    add_app_log(format!("RustFS status: {:?}", status));
+    *RUSTFS_STATUS.lock().unwrap() = status.clone();
+    emit_event(events::RUSTFS_STATUS, status);
+}
+
+pub fn set_shutdown_timeout(timeout: Duration) {
+    *SHUTDOWN_TIMEOUT.lock().unwrap() = timeout;
+}
+
+pub fn terminate_rustfs_process() -> Option<ExitStatus> {
+    // Leave a Stopping marker so the lock is not held during the grace period and no
+    // launch can start until the process is gone
+    let process = {
+        let mut slot = RUSTFS_PROCESS.lock().unwrap();
+        match std::mem::replace(&mut *slot, ProcessSlot::Empty) {
+            ProcessSlot::Running(child) => {
+                *slot = ProcessSlot::Stopping(child.id());
+                Some(child)
             }
-            Err(e) => {
-                add_app_log(format!("Failed to terminate RustFS process: {}", e));
+            other => {
+                *slot = other;
+                None
             }
         }
+    };
+    if let Some(process) = process {
+        let pid = process.id();
+        add_app_log(format!("Terminating RustFS process with PID: {}", pid));
+        let timeout = *SHUTDOWN_TIMEOUT.lock().unwrap();
+        let status = crate::process::shutdown(process, timeout);
+        let mut slot = RUSTFS_PROCESS.lock().unwrap();
+        if matches!(*slot, ProcessSlot::Stopping(stopping) if stopping == pid) {
+            *slot = ProcessSlot::Empty;
+        }
+        status
     } else {
         add_app_log("No RustFS process to terminate".to_string());
+        None
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[test]
+    fn the_process_slot_admits_one_launch() {
+        let token = reserve_rustfs_slot().unwrap();
+        assert!(matches!(reserve_rustfs_slot(), Err(Error::ProcessBusy)));
+
+        let child = Command::new("sleep").arg("30").spawn().unwrap();
+        // A stale token must not displace the reservation
+        let child = set_rustfs_process(token + 1, child).unwrap_err();
+        let pid = child.id();
+        set_rustfs_process(token, child).unwrap();
+        release_rustfs_slot(token);
+        assert_eq!(running_rustfs_pid(), Some(pid));
+        assert!(matches!(
+            reserve_rustfs_slot(),
+            Err(Error::AlreadyRunning { pid: running }) if running == pid
+        ));
+
+        terminate_rustfs_process();
+        assert!(!has_rustfs_process());
+        release_rustfs_slot(reserve_rustfs_slot().unwrap());
     }
 }
@@ -0,0 +1,24 @@
+use rustfs_launcher_shared::status::RustFsStatus;
+use std::process::ExitStatus;
+
+/// Classifies an exit the launcher did not ask for.
+pub fn from_exit_status(status: ExitStatus) -> RustFsStatus {
+    if status.success() {
+        return RustFsStatus::Exited {
+            code: status.code(),
+        };
+    }
+
+    #[cfg(unix)]
+    let signal = {
+        use std::os::unix::process::ExitStatusExt;
+        status.signal()
+    };
+    #[cfg(not(unix))]
+    let signal = None;
+
+    RustFsStatus::Crashed {
+        code: status.code(),
+        signal,
+    }
+}
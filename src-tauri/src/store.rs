@@ -0,0 +1,591 @@
+use crate::error::{Error, Result};
+use crate::migrate;
+use crate::secrets;
+use crate::state::add_app_log;
+use rustfs_launcher_shared::config::{RustFsConfig, CURRENT_SCHEMA_VERSION, DEFAULT_CREDENTIAL};
+use rustfs_launcher_shared::ipc::ProfileList;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+// Single-config file written before profiles existed; imported as the default profile
+const LEGACY_CONFIG_FILE: &str = "config.json";
+const PROFILES_FILE: &str = "profiles.json";
+const DEFAULT_PROFILE: &str = "default";
+
+// Serializes every read-modify-write of the profiles file
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ProfileStore {
+    pub last_used: Option<String>,
+    pub profiles: BTreeMap<String, RustFsConfig>,
+}
+
+impl ProfileStore {
+    pub fn list(&self) -> ProfileList {
+        ProfileList {
+            names: self.profiles.keys().cloned().collect(),
+            last_used: self.last_used.clone(),
+        }
+    }
+
+    pub fn active_name(&self) -> String {
+        self.last_used
+            .clone()
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+    }
+
+    pub fn active(&self) -> Option<&RustFsConfig> {
+        self.profiles.get(&self.active_name())
+    }
+
+    pub fn select(&mut self, name: &str) -> Result<RustFsConfig> {
+        let config = self.get(name)?.clone();
+        self.last_used = Some(name.to_string());
+        Ok(config)
+    }
+
+    pub fn create(&mut self, name: &str, config: RustFsConfig) -> Result<()> {
+        let name = validate_profile_name(name)?;
+        if self.profiles.contains_key(&name) {
+            return Err(Error::ProfileExists(name));
+        }
+        self.profiles.insert(name.clone(), config);
+        self.last_used = Some(name);
+        Ok(())
+    }
+
+    pub fn rename(&mut self, name: &str, new_name: &str) -> Result<()> {
+        let new_name = validate_profile_name(new_name)?;
+        if self.profiles.contains_key(&new_name) {
+            return Err(Error::ProfileExists(new_name));
+        }
+        let config = self
+            .profiles
+            .remove(name)
+            .ok_or_else(|| Error::ProfileNotFound(name.to_string()))?;
+        self.profiles.insert(new_name.clone(), config);
+        if self.last_used.as_deref() == Some(name) {
+            self.last_used = Some(new_name);
+        }
+        Ok(())
+    }
+
+    /// Copies a profile and returns the copy.
+    pub fn duplicate(&mut self, name: &str, new_name: &str) -> Result<&mut RustFsConfig> {
+        let config = self.get(name)?.clone();
+        let new_name = validate_profile_name(new_name)?;
+        self.create(&new_name, config)?;
+        Ok(self
+            .profiles
+            .get_mut(&new_name)
+            .expect("profile was just created"))
+    }
+
+    /// Removes a profile and returns its config.
+    pub fn delete(&mut self, name: &str) -> Result<RustFsConfig> {
+        let config = self
+            .profiles
+            .remove(name)
+            .ok_or_else(|| Error::ProfileNotFound(name.to_string()))?;
+        if self.last_used.as_deref() == Some(name) {
+            self.last_used = self.profiles.keys().next().cloned();
+        }
+        Ok(config)
+    }
+
+    /// Stores `config` under the last-used profile, creating it if needed.
+    pub fn save_active(&mut self, config: RustFsConfig) {
+        let name = self.active_name();
+        self.profiles.insert(name.clone(), config);
+        self.last_used = Some(name);
+    }
+
+    fn get(&self, name: &str) -> Result<&RustFsConfig> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| Error::ProfileNotFound(name.to_string()))
+    }
+}
+
+fn validate_profile_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error::InvalidProfileName(name.to_string()));
+    }
+    Ok(name.to_string())
+}
+
+pub fn config_dir(app: &AppHandle) -> Result<PathBuf> {
+    app.path()
+        .app_config_dir()
+        .map_err(|e| Error::ConfigDirUnavailable(e.to_string()))
+}
+
+/// Writes `contents` to a sibling temp file and renames it over `path`.
+///
+/// Each call uses its own temp file, so concurrent writers never clobber one another.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let parent = path.parent().unwrap_or_else(|| Path::new("."));
+    std::fs::create_dir_all(parent)?;
+
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let tmp_path = parent.join(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    {
+        let mut file = std::fs::File::create(&tmp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+    }
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Loads the profile store, importing the legacy single config on first use.
+///
+/// Profiles saved by older launchers are migrated, and the original file is kept as
+/// `profiles.json.v<N>.bak`, minus its secret keys, before the upgraded store is written
+/// back. Secret keys still saved in plain text are moved into their secret backend, and the
+/// legacy `config.json` keeps none once imported.
+pub fn load_profiles(dir: &Path) -> Result<ProfileStore> {
+    let _guard = STORE_LOCK.lock().unwrap();
+    load_locked(dir)
+}
+
+fn load_locked(dir: &Path) -> Result<ProfileStore> {
+    let path = dir.join(PROFILES_FILE);
+    if path.exists() {
+        let contents = std::fs::read_to_string(&path)?;
+        let original: Value = serde_json::from_str(&contents)?;
+        let mut document = original.clone();
+        let oldest = migrate_profiles(&mut document)?;
+        let mut store: ProfileStore = serde_json::from_value(document)?;
+
+        if let Some(version) = oldest {
+            let backup = backup_file(&path, original, version)?;
+            add_app_log(format!(
+                "Migrated profiles from schema version {} to {} (backup: {})",
+                version,
+                CURRENT_SCHEMA_VERSION,
+                backup.display()
+            ));
+        }
+        let sealed = seal_plaintext_secrets(&mut store);
+        if sealed {
+            scrub_backups(&path);
+        }
+        if oldest.is_some() || sealed {
+            save_locked(dir, &store)?;
+        }
+        return Ok(store);
+    }
+
+    let mut store = ProfileStore::default();
+    let legacy_path = dir.join(LEGACY_CONFIG_FILE);
+    if legacy_path.exists() {
+        let contents = std::fs::read_to_string(&legacy_path)?;
+        let mut document: Value = serde_json::from_str(&contents)?;
+        migrate::migrate_config(&mut document)?;
+        let config: RustFsConfig = serde_json::from_value(document)?;
+        store.save_active(config);
+        add_app_log(format!(
+            "Imported {} as profile \"{}\"",
+            legacy_path.display(),
+            DEFAULT_PROFILE
+        ));
+        seal_plaintext_secrets(&mut store);
+        // Written right away so the import, and any secret it sealed, happens only once
+        save_locked(dir, &store)?;
+        if let Err(err) = scrub_legacy_config(&legacy_path) {
+            add_app_log(format!(
+                "Failed to remove the secret key from {}: {}",
+                legacy_path.display(),
+                err
+            ));
+        }
+    }
+    Ok(store)
+}
+
+/// Migrates every profile and returns the oldest schema version seen, if any was outdated.
+fn migrate_profiles(document: &mut Value) -> Result<Option<u32>> {
+    let mut oldest: Option<u32> = None;
+    if let Some(profiles) = document.get_mut("profiles").and_then(Value::as_object_mut) {
+        for config in profiles.values_mut() {
+            let from = migrate::migrate_config(config)?;
+            if from < CURRENT_SCHEMA_VERSION {
+                oldest = Some(oldest.map_or(from, |version| version.min(from)));
+            }
+        }
+    }
+    Ok(oldest)
+}
+
+/// Moves plain-text secret keys into their backend, returning whether any were moved.
+///
+/// A key that cannot be moved yet, e.g. because the vault is locked, is kept as it is
+/// and tried again on the next load.
+fn seal_plaintext_secrets(store: &mut ProfileStore) -> bool {
+    let mut sealed = false;
+    for (name, config) in &mut store.profiles {
+        if matches!(
+            config.secret_key.as_deref(),
+            None | Some(DEFAULT_CREDENTIAL)
+        ) {
+            continue;
+        }
+        let mut candidate = config.clone();
+        match secrets::seal(&mut candidate, None) {
+            Ok(()) => {
+                *config = candidate;
+                sealed = true;
+            }
+            Err(err) => add_app_log(format!(
+                "Secret key of profile \"{}\" is still saved in plain text: {}",
+                name, err
+            )),
+        }
+    }
+    sealed
+}
+
+/// Drops a non-default secret key from one config document, returning whether it had one.
+fn scrub_config(config: &mut Value) -> bool {
+    let Some(config) = config.as_object_mut() else {
+        return false;
+    };
+    let plaintext = config
+        .get("secret_key")
+        .and_then(Value::as_str)
+        .is_some_and(|secret| secret != DEFAULT_CREDENTIAL);
+    if plaintext {
+        config.remove("secret_key");
+    }
+    plaintext
+}
+
+/// Drops every non-default secret key from a profiles document, returning whether any were found.
+fn scrub_secrets(document: &mut Value) -> bool {
+    let mut scrubbed = false;
+    if let Some(profiles) = document.get_mut("profiles").and_then(Value::as_object_mut) {
+        for config in profiles.values_mut() {
+            scrubbed |= scrub_config(config);
+        }
+    }
+    scrubbed
+}
+
+/// Removes the secret key from the legacy config once it lives in `profiles.json`.
+fn scrub_legacy_config(path: &Path) -> Result<()> {
+    let mut document: Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    if scrub_config(&mut document) {
+        write_atomic(path, &serde_json::to_vec_pretty(&document)?)?;
+    }
+    Ok(())
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Keeps `original` next to `path`, minus its secret keys, which now live in their backend.
+fn backup_file(path: &Path, mut original: Value, version: u32) -> Result<PathBuf> {
+    let backup = path.with_file_name(format!("{}.v{}.bak", file_name(path), version));
+    scrub_secrets(&mut original);
+    write_atomic(&backup, &serde_json::to_vec_pretty(&original)?)?;
+    Ok(backup)
+}
+
+/// Removes secret keys from backups written before they were scrubbed.
+fn scrub_backups(path: &Path) {
+    let Some(dir) = path.parent() else { return };
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let prefix = format!("{}.v", file_name(path));
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.starts_with(&prefix) || !name.ends_with(".bak") {
+            continue;
+        }
+        let backup = entry.path();
+        if let Err(err) = scrub_backup(&backup) {
+            add_app_log(format!(
+                "Failed to remove secret keys from {}: {}",
+                backup.display(),
+                err
+            ));
+        }
+    }
+}
+
+fn scrub_backup(backup: &Path) -> Result<()> {
+    let mut document: Value = serde_json::from_str(&std::fs::read_to_string(backup)?)?;
+    if scrub_secrets(&mut document) {
+        write_atomic(backup, &serde_json::to_vec_pretty(&document)?)?;
+    }
+    Ok(())
+}
+
+pub fn save_profiles(dir: &Path, store: &ProfileStore) -> Result<()> {
+    let _guard = STORE_LOCK.lock().unwrap();
+    save_locked(dir, store)
+}
+
+fn save_locked(dir: &Path, store: &ProfileStore) -> Result<()> {
+    let path = dir.join(PROFILES_FILE);
+    let contents = serde_json::to_vec_pretty(store)?;
+    write_atomic(&path, &contents)?;
+    add_app_log(format!("Saved profiles to {}", path.display()));
+    Ok(())
+}
+
+/// Loads the store, applies `change`, and writes it back atomically.
+///
+/// Holds the store lock throughout, so concurrent commands cannot lose each other's updates.
+pub fn update_profiles<T>(
+    dir: &Path,
+    change: impl FnOnce(&mut ProfileStore) -> Result<T>,
+) -> Result<T> {
+    let _guard = STORE_LOCK.lock().unwrap();
+    let mut store = load_locked(dir)?;
+    let result = change(&mut store)?;
+    save_locked(dir, &store)?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault;
+    use rustfs_launcher_shared::config::SecretBackend;
+
+    const PROFILES_V0: &str = include_str!("../fixtures/profiles_v0.json");
+
+    #[test]
+    fn old_profiles_are_backed_up_and_rewritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PROFILES_FILE);
+        std::fs::write(&path, PROFILES_V0).unwrap();
+
+        let store = load_profiles(dir.path()).unwrap();
+        assert_eq!(store.last_used.as_deref(), Some("scratch"));
+        assert_eq!(store.profiles["fixtures"].port, Some(9200));
+        assert_eq!(
+            store.profiles["fixtures"].access_key.as_deref(),
+            Some("rustfsadmin")
+        );
+
+        let backup: Value = serde_json::from_str(
+            &std::fs::read_to_string(dir.path().join("profiles.json.v0.bak")).unwrap(),
+        )
+        .unwrap();
+        let mut original: Value = serde_json::from_str(PROFILES_V0).unwrap();
+        original["profiles"]["scratch"]
+            .as_object_mut()
+            .unwrap()
+            .remove("secret_key");
+        assert_eq!(backup, original);
+
+        let scratch = &store.profiles["scratch"];
+        assert_eq!(scratch.secret_key, None);
+        assert_eq!(
+            secrets::resolve(scratch).unwrap().secret_key.as_deref(),
+            Some("scratch-secret-key")
+        );
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("scratch-secret-key"));
+        let rewritten: Value = serde_json::from_str(&contents).unwrap();
+        for config in rewritten["profiles"].as_object().unwrap().values() {
+            assert_eq!(migrate::schema_version(config), CURRENT_SCHEMA_VERSION);
+        }
+    }
+
+    #[test]
+    fn current_profiles_are_not_rewritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = ProfileStore::default();
+        store.save_active(RustFsConfig::default());
+        save_profiles(dir.path(), &store).unwrap();
+
+        load_profiles(dir.path()).unwrap();
+        let backups = std::fs::read_dir(dir.path())
+            .unwrap()
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().ends_with(".bak"))
+            .count();
+        assert_eq!(backups, 0);
+    }
+
+    #[test]
+    fn plaintext_secrets_are_sealed_on_load() {
+        let dir = tempfile::tempdir().unwrap();
+        vault::unlock(dir.path(), "correct horse").unwrap();
+        let mut store = ProfileStore::default();
+        store.save_active(RustFsConfig {
+            secret_key: Some("launcher-secret".to_string()),
+            secret_backend: SecretBackend::Vault,
+            ..RustFsConfig::default()
+        });
+        save_profiles(dir.path(), &store).unwrap();
+        let backup = dir.path().join("profiles.json.v0.bak");
+        std::fs::write(&backup, PROFILES_V0).unwrap();
+
+        let loaded = load_profiles(dir.path()).unwrap();
+        let config = loaded.active().unwrap();
+        assert_eq!(config.secret_key, None);
+        assert!(config.secret_ref.is_some());
+        assert_eq!(
+            secrets::resolve(config).unwrap().secret_key.as_deref(),
+            Some("launcher-secret")
+        );
+
+        let saved = std::fs::read_to_string(dir.path().join(PROFILES_FILE)).unwrap();
+        assert!(!saved.contains("launcher-secret"));
+        assert!(!std::fs::read_to_string(&backup)
+            .unwrap()
+            .contains("scratch-secret-key"));
+    }
+
+    #[test]
+    fn legacy_config_is_imported_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let legacy_path = dir.path().join(LEGACY_CONFIG_FILE);
+        std::fs::write(
+            &legacy_path,
+            r#"{"data_path": "/srv/rustfs", "port": 9300, "secret_key": "legacy-secret"}"#,
+        )
+        .unwrap();
+
+        let first = load_profiles(dir.path()).unwrap();
+        let config = first.active().unwrap();
+        assert_eq!(config.port, Some(9300));
+        assert_eq!(config.secret_key, None);
+        assert_eq!(
+            secrets::resolve(config).unwrap().secret_key.as_deref(),
+            Some("legacy-secret")
+        );
+
+        let second = load_profiles(dir.path()).unwrap();
+        assert!(second.active().unwrap().secret_ref.is_some());
+        assert_eq!(second.active().unwrap().secret_ref, config.secret_ref);
+        for file in [LEGACY_CONFIG_FILE, PROFILES_FILE] {
+            let contents = std::fs::read_to_string(dir.path().join(file)).unwrap();
+            assert!(!contents.contains("legacy-secret"), "{}", file);
+        }
+    }
+
+    fn store_with(names: &[&str]) -> ProfileStore {
+        let mut store = ProfileStore::default();
+        for (port, name) in (9000..).zip(names) {
+            let config = RustFsConfig {
+                port: Some(port),
+                ..RustFsConfig::default()
+            };
+            store.create(name, config).unwrap();
+        }
+        store
+    }
+
+    #[test]
+    fn rename_moves_the_profile_and_its_selection() {
+        let mut store = store_with(&["dev", "prod"]);
+        store.select("dev").unwrap();
+        store.rename("dev", " staging ").unwrap();
+        assert_eq!(store.list().names, ["prod", "staging"]);
+        assert_eq!(store.last_used.as_deref(), Some("staging"));
+        assert_eq!(store.profiles["staging"].port, Some(9000));
+
+        assert!(matches!(
+            store.rename("staging", "prod"),
+            Err(Error::ProfileExists(name)) if name == "prod"
+        ));
+        assert!(matches!(
+            store.rename("missing", "other"),
+            Err(Error::ProfileNotFound(_))
+        ));
+        assert!(matches!(
+            store.rename("prod", "  "),
+            Err(Error::InvalidProfileName(_))
+        ));
+        assert_eq!(store.list().names, ["prod", "staging"]);
+    }
+
+    #[test]
+    fn duplicate_copies_without_replacing() {
+        let mut store = store_with(&["dev", "prod"]);
+        store.duplicate("dev", "dev copy").unwrap().port = Some(9500);
+        assert_eq!(store.profiles["dev"].port, Some(9000));
+        assert_eq!(store.profiles["dev copy"].port, Some(9500));
+        assert_eq!(store.last_used.as_deref(), Some("dev copy"));
+
+        assert!(matches!(
+            store.duplicate("dev", "prod"),
+            Err(Error::ProfileExists(_))
+        ));
+        assert_eq!(store.profiles["prod"].port, Some(9001));
+        assert!(matches!(
+            store.duplicate("missing", "other"),
+            Err(Error::ProfileNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn deleting_the_active_profile_selects_another() {
+        let mut store = store_with(&["dev", "prod"]);
+        assert_eq!(store.last_used.as_deref(), Some("prod"));
+        assert_eq!(store.delete("prod").unwrap().port, Some(9001));
+        assert_eq!(store.last_used.as_deref(), Some("dev"));
+
+        store.delete("dev").unwrap();
+        assert_eq!(store.last_used, None);
+        assert!(store.active().is_none());
+        assert!(matches!(
+            store.delete("dev"),
+            Err(Error::ProfileNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn concurrent_updates_are_not_lost() {
+        let dir = tempfile::tempdir().unwrap();
+        std::thread::scope(|scope| {
+            for i in 0..8 {
+                let dir = dir.path();
+                scope.spawn(move || {
+                    update_profiles(dir, |profiles| {
+                        profiles.create(&format!("profile-{}", i), RustFsConfig::default())
+                    })
+                    .unwrap();
+                });
+            }
+        });
+
+        assert_eq!(load_profiles(dir.path()).unwrap().profiles.len(), 8);
+        let leftovers = std::fs::read_dir(dir.path())
+            .unwrap()
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+            .count();
+        assert_eq!(leftovers, 0);
+    }
+}
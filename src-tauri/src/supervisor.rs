@@ -0,0 +1,209 @@
+use crate::health;
+use crate::process;
+use crate::state::{add_app_log, add_rustfs_log, set_rustfs_status, ProcessSlot, RUSTFS_PROCESS};
+use crate::status;
+use rustfs_launcher_shared::config::{RestartPolicy, RustFsConfig};
+use rustfs_launcher_shared::status::RustFsStatus;
+use std::process::ExitStatus;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const EXIT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+// A run this long counts as healthy and resets the restart budget
+const STABLE_RUN: Duration = Duration::from_secs(60);
+
+// Bumped on every launch and stop; a supervisor exits once its generation is stale.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+pub fn start(config: RustFsConfig, pid: u32) {
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    health::start(generation, config.clone());
+    thread::spawn(move || {
+        supervise(generation, config, pid);
+        retire(generation);
+    });
+}
+
+pub fn cancel() {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+pub fn is_current(generation: u64) -> bool {
+    GENERATION.load(Ordering::SeqCst) == generation
+}
+
+// Ends a generation without disturbing one that a newer launch already started
+fn retire(generation: u64) {
+    let _ = GENERATION.compare_exchange(
+        generation,
+        generation + 1,
+        Ordering::SeqCst,
+        Ordering::SeqCst,
+    );
+}
+
+/// Publishes `status` unless a stop or a newer launch has taken over; `false` means stale.
+fn report(generation: u64, status: RustFsStatus) -> bool {
+    if !is_current(generation) {
+        return false;
+    }
+    set_rustfs_status(status);
+    true
+}
+
+fn should_restart(policy: RestartPolicy, failed: bool) -> bool {
+    match policy {
+        RestartPolicy::Never => false,
+        RestartPolicy::OnFailure => failed,
+        RestartPolicy::Always => true,
+    }
+}
+
+fn backoff_delay(base_secs: u64, attempt: u32) -> Duration {
+    let factor = 1u64 << attempt.min(16);
+    Duration::from_secs(base_secs.saturating_mul(factor)).min(MAX_BACKOFF)
+}
+
+fn supervise(generation: u64, config: RustFsConfig, pid: u32) {
+    let mut current_pid = Some(pid);
+    let mut restarts = 0u32;
+
+    loop {
+        let failed = match current_pid {
+            Some(pid) => {
+                let started_at = Instant::now();
+                let status = match wait_for_exit(generation, pid) {
+                    Some(status) => status,
+                    None => return,
+                };
+                add_app_log(format!("RustFS process (PID: {}) exited: {}", pid, status));
+                add_rustfs_log(format!("RustFS process exited: {}", status));
+                if !report(generation, status::from_exit_status(status)) {
+                    return;
+                }
+
+                if started_at.elapsed() >= STABLE_RUN {
+                    restarts = 0;
+                }
+                !status.success()
+            }
+            // The previous restart attempt failed to spawn
+            None => true,
+        };
+
+        if !should_restart(config.restart_policy, failed) {
+            return;
+        }
+        if restarts >= config.max_restarts {
+            add_app_log(format!(
+                "RustFS restart limit reached ({} attempts), giving up",
+                restarts
+            ));
+            report(generation, RustFsStatus::GaveUp { restarts });
+            return;
+        }
+
+        let delay = backoff_delay(config.restart_backoff_secs, restarts);
+        restarts += 1;
+        add_app_log(format!(
+            "Restarting RustFS in {}s (attempt {}/{}, policy: {:?})",
+            delay.as_secs(),
+            restarts,
+            config.max_restarts,
+            config.restart_policy
+        ));
+        let restarting = RustFsStatus::Restarting {
+            attempt: restarts,
+            delay_secs: delay.as_secs(),
+        };
+        if !report(generation, restarting) || !sleep_while_current(generation, delay) {
+            add_app_log("Pending RustFS restart cancelled".to_string());
+            return;
+        }
+
+        add_rustfs_log(format!("--- restart attempt {} ---", restarts));
+        current_pid = match process::spawn(&config) {
+            Ok(pid) => Some(pid),
+            Err(e) => {
+                add_app_log(format!("Failed to restart RustFS: {}", e));
+                let crashed = RustFsStatus::Crashed {
+                    code: None,
+                    signal: None,
+                };
+                // A stop during startup already reported its own status
+                if !report(generation, crashed) {
+                    return;
+                }
+                None
+            }
+        };
+    }
+}
+
+/// Polls the tracked child until it exits; `None` means it was stopped or replaced.
+fn wait_for_exit(generation: u64, pid: u32) -> Option<ExitStatus> {
+    loop {
+        thread::sleep(EXIT_POLL_INTERVAL);
+        if !is_current(generation) {
+            return None;
+        }
+
+        let mut slot = RUSTFS_PROCESS.lock().unwrap();
+        // Whoever stopped or replaced the process reports the status
+        let child = slot.child(pid)?;
+
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                *slot = ProcessSlot::Empty;
+                return Some(status);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                add_app_log(format!(
+                    "Failed to poll RustFS process (PID: {}): {}",
+                    pid, e
+                ));
+                return None;
+            }
+        }
+    }
+}
+
+fn sleep_while_current(generation: u64, delay: Duration) -> bool {
+    let deadline = Instant::now() + delay;
+    while Instant::now() < deadline {
+        if !is_current(generation) {
+            return false;
+        }
+        thread::sleep(EXIT_POLL_INTERVAL);
+    }
+    is_current(generation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restarts_follow_the_policy() {
+        for failed in [false, true] {
+            assert!(!should_restart(RestartPolicy::Never, failed));
+            assert!(should_restart(RestartPolicy::Always, failed));
+        }
+        assert!(should_restart(RestartPolicy::OnFailure, true));
+        assert!(!should_restart(RestartPolicy::OnFailure, false));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        assert_eq!(backoff_delay(2, 0), Duration::from_secs(2));
+        assert_eq!(backoff_delay(2, 1), Duration::from_secs(4));
+        assert_eq!(backoff_delay(2, 4), Duration::from_secs(32));
+        assert_eq!(backoff_delay(0, 3), Duration::ZERO);
+        assert_eq!(backoff_delay(2, 10), MAX_BACKOFF);
+        assert_eq!(backoff_delay(2, u32::MAX), MAX_BACKOFF);
+        assert_eq!(backoff_delay(u64::MAX, 1), MAX_BACKOFF);
+    }
+}
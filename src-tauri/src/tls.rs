@@ -0,0 +1,353 @@
+//! Local certificate authority and server certificates for serving RustFS over HTTPS.
+//!
+//! The CA is created once and reused, so clients only need to trust it a single time;
+//! the server certificate is reissued at launch once it no longer covers the listen host.
+
+use crate::error::{Error, Result};
+use crate::state::add_app_log;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{Datelike, Days, Utc};
+use rcgen::{
+    date_time_ymd, BasicConstraints, CertificateParams, DistinguishedName, DnType,
+    ExtendedKeyUsagePurpose, IsCa, Issuer, KeyPair, KeyUsagePurpose,
+};
+use rustfs_launcher_shared::config::RustFsConfig;
+use rustfs_launcher_shared::ipc::TlsInfo;
+use sha2::{Digest, Sha256};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use x509_parser::extensions::GeneralName;
+
+const CA_DIR: &str = "ca";
+const CA_CERT_FILE: &str = "ca_cert.pem";
+const CA_KEY_FILE: &str = "ca_key.pem";
+const TLS_DIR: &str = "tls";
+// File names RustFS looks for in `--tls-path`
+const CERT_FILE: &str = "rustfs_cert.pem";
+const KEY_FILE: &str = "rustfs_key.pem";
+
+const CA_NAME: &str = "RustFS Launcher Local CA";
+const CA_VALIDITY_DAYS: u64 = 3650;
+// Clients such as Safari reject server certificates valid for more than 398 days
+const SERVER_VALIDITY_DAYS: u64 = 397;
+
+/// Issues a server certificate for `host` and writes it where RustFS expects it.
+pub fn generate(dir: &Path, host: &str) -> Result<TlsInfo> {
+    let (ca_pem, ca_key) = load_or_create_ca(&dir.join(CA_DIR))?;
+    let issuer = Issuer::from_ca_cert_pem(&ca_pem, ca_key)?;
+
+    let hosts = server_hosts(host);
+    let mut params = CertificateParams::new(hosts.clone())?;
+    params.distinguished_name = distinguished_name(&hosts[0]);
+    params.key_usages = vec![
+        KeyUsagePurpose::DigitalSignature,
+        KeyUsagePurpose::KeyEncipherment,
+    ];
+    params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+    params.use_authority_key_identifier_extension = true;
+    set_validity(&mut params, SERVER_VALIDITY_DAYS);
+
+    let key = KeyPair::generate()?;
+    let cert = params.signed_by(&key, &issuer)?;
+
+    let tls_dir = dir.join(TLS_DIR);
+    std::fs::create_dir_all(&tls_dir)?;
+    write_private(&tls_dir.join(KEY_FILE), key.serialize_pem().as_bytes())?;
+    std::fs::write(tls_dir.join(CERT_FILE), cert.pem())?;
+    add_app_log(format!(
+        "Issued TLS certificate for {} in {}",
+        hosts.join(", "),
+        tls_dir.display()
+    ));
+
+    Ok(TlsInfo {
+        tls_path: tls_dir.display().to_string(),
+        ca_fingerprint: fingerprint(&ca_pem)?,
+        hosts,
+    })
+}
+
+/// Reissues the launcher's server certificate when it no longer covers `config`'s host.
+///
+/// Certificates in any other directory belong to the user, so a mismatch there is only logged.
+pub fn refresh(dir: &Path, config: &RustFsConfig) -> Result<()> {
+    let Some(tls_path) = config.tls_path.as_deref().map(Path::new) else {
+        return Ok(());
+    };
+    let host = config.host.as_deref().unwrap_or("127.0.0.1");
+    let covered = covers(&tls_path.join(CERT_FILE), host);
+    if tls_path == dir.join(TLS_DIR) {
+        if !matches!(covered, Ok(true)) {
+            add_app_log(format!(
+                "TLS certificate does not cover {}; issuing a new one",
+                host
+            ));
+            generate(dir, host)?;
+        }
+    } else if let Ok(false) = covered {
+        add_app_log(format!(
+            "TLS certificate in {} does not cover {}; clients may reject it",
+            tls_path.display(),
+            host
+        ));
+    }
+    Ok(())
+}
+
+/// Whether the certificate at `cert_path` names every host [`generate`] would issue it for.
+fn covers(cert_path: &Path, host: &str) -> Result<bool> {
+    let der = pem_der(&std::fs::read_to_string(cert_path)?)?;
+    let (_, cert) = x509_parser::parse_x509_certificate(&der)
+        .map_err(|_| rcgen::Error::CouldNotParseCertificate)?;
+    let names: Vec<String> = match cert.subject_alternative_name() {
+        Ok(Some(extension)) => extension
+            .value
+            .general_names
+            .iter()
+            .filter_map(|name| match name {
+                GeneralName::DNSName(name) => Some(name.to_ascii_lowercase()),
+                GeneralName::IPAddress([a, b, c, d]) => {
+                    Some(IpAddr::from([*a, *b, *c, *d]).to_string())
+                }
+                GeneralName::IPAddress(bytes) => <[u8; 16]>::try_from(*bytes)
+                    .ok()
+                    .map(|octets| IpAddr::from(octets).to_string()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    Ok(server_hosts(host).iter().all(|host| {
+        let host = match host.parse::<IpAddr>() {
+            Ok(ip) => ip.to_string(),
+            Err(_) => host.to_ascii_lowercase(),
+        };
+        names.contains(&host)
+    }))
+}
+
+/// The local CA's SHA-256 fingerprint, if the CA has been created.
+pub fn ca_fingerprint(dir: &Path) -> Result<Option<String>> {
+    let path = ca_cert_path(dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+    fingerprint(&std::fs::read_to_string(path)?).map(Some)
+}
+
+/// Copies the CA certificate to `path` so clients can be told to trust it.
+pub fn export_ca(dir: &Path, path: &Path) -> Result<()> {
+    let source = ca_cert_path(dir);
+    if !source.exists() {
+        return Err(Error::TlsCaMissing);
+    }
+    std::fs::copy(&source, path)?;
+    add_app_log(format!("Exported TLS CA certificate to {}", path.display()));
+    Ok(())
+}
+
+fn ca_cert_path(dir: &Path) -> PathBuf {
+    dir.join(CA_DIR).join(CA_CERT_FILE)
+}
+
+/// Returns the CA certificate PEM and signing key, creating both on first use.
+fn load_or_create_ca(ca_dir: &Path) -> Result<(String, KeyPair)> {
+    let cert_path = ca_dir.join(CA_CERT_FILE);
+    let key_path = ca_dir.join(CA_KEY_FILE);
+    if cert_path.exists() && key_path.exists() {
+        let key = KeyPair::from_pem(&std::fs::read_to_string(&key_path)?)?;
+        return Ok((std::fs::read_to_string(&cert_path)?, key));
+    }
+
+    let mut params = CertificateParams::new(Vec::new())?;
+    params.distinguished_name = distinguished_name(CA_NAME);
+    params.is_ca = IsCa::Ca(BasicConstraints::Constrained(0));
+    params.key_usages = vec![
+        KeyUsagePurpose::KeyCertSign,
+        KeyUsagePurpose::CrlSign,
+        KeyUsagePurpose::DigitalSignature,
+    ];
+    set_validity(&mut params, CA_VALIDITY_DAYS);
+
+    let key = KeyPair::generate()?;
+    let cert = params.self_signed(&key)?;
+    std::fs::create_dir_all(ca_dir)?;
+    write_private(&key_path, key.serialize_pem().as_bytes())?;
+    std::fs::write(&cert_path, cert.pem())?;
+    add_app_log(format!("Created local TLS CA in {}", ca_dir.display()));
+    Ok((cert.pem(), key))
+}
+
+/// The configured host plus the loopback names, skipping wildcard binds and duplicates.
+fn server_hosts(host: &str) -> Vec<String> {
+    let host = host
+        .strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host);
+    let wildcard = host.parse::<IpAddr>().is_ok_and(|ip| ip.is_unspecified());
+
+    let mut hosts = Vec::new();
+    if !host.is_empty() && !wildcard {
+        hosts.push(host.to_string());
+    }
+    for name in ["localhost", "127.0.0.1"] {
+        if !hosts.iter().any(|existing| existing == name) {
+            hosts.push(name.to_string());
+        }
+    }
+    hosts
+}
+
+fn distinguished_name(common_name: &str) -> DistinguishedName {
+    let mut name = DistinguishedName::new();
+    name.push(DnType::CommonName, common_name);
+    name
+}
+
+fn set_validity(params: &mut CertificateParams, days: u64) {
+    let today = Utc::now().date_naive();
+    let until = today + Days::new(days);
+    params.not_before = date_time_ymd(today.year(), today.month() as u8, today.day() as u8);
+    params.not_after = date_time_ymd(until.year(), until.month() as u8, until.day() as u8);
+}
+
+/// The DER encoding inside a single-certificate PEM file.
+fn pem_der(cert_pem: &str) -> Result<Vec<u8>> {
+    let body: String = cert_pem
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    Ok(BASE64
+        .decode(body.trim())
+        .map_err(|_| rcgen::Error::CouldNotParseCertificate)?)
+}
+
+/// SHA-256 of the certificate's DER encoding as colon-separated hex.
+fn fingerprint(cert_pem: &str) -> Result<String> {
+    Ok(Sha256::digest(pem_der(cert_pem)?)
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<_>>()
+        .join(":"))
+}
+
+/// Writes a private key readable only by the current user.
+fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    // The mode above only applies to new files; tighten an existing one before writing
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issues_server_certificates_from_one_ca() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = generate(dir.path(), "0.0.0.0").unwrap();
+        assert_eq!(first.hosts, ["localhost", "127.0.0.1"]);
+        assert_eq!(
+            ca_fingerprint(dir.path()).unwrap().as_deref(),
+            Some(first.ca_fingerprint.as_str())
+        );
+
+        let tls_dir = Path::new(&first.tls_path);
+        let cert = std::fs::read_to_string(tls_dir.join(CERT_FILE)).unwrap();
+        assert!(cert.starts_with("-----BEGIN CERTIFICATE-----"));
+        assert!(std::fs::read_to_string(tls_dir.join(KEY_FILE))
+            .unwrap()
+            .contains("PRIVATE KEY"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(tls_dir.join(KEY_FILE))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let second = generate(dir.path(), "s3.example.com").unwrap();
+        assert_eq!(second.hosts, ["s3.example.com", "localhost", "127.0.0.1"]);
+        assert_eq!(second.ca_fingerprint, first.ca_fingerprint);
+        assert_ne!(
+            std::fs::read_to_string(tls_dir.join(CERT_FILE)).unwrap(),
+            cert
+        );
+    }
+
+    #[test]
+    fn reissues_only_the_launchers_certificate() {
+        let dir = tempfile::tempdir().unwrap();
+        let issued = generate(dir.path(), "127.0.0.1").unwrap();
+        let cert_path = Path::new(&issued.tls_path).join(CERT_FILE);
+        assert!(covers(&cert_path, "0.0.0.0").unwrap());
+        assert!(!covers(&cert_path, "s3.example.com").unwrap());
+
+        let mut config = RustFsConfig {
+            host: Some("s3.example.com".to_string()),
+            tls_path: Some(issued.tls_path.clone()),
+            ..RustFsConfig::default()
+        };
+        let user_dir = tempfile::tempdir().unwrap();
+        let user_cert = user_dir.path().join(CERT_FILE);
+        std::fs::copy(&cert_path, &user_cert).unwrap();
+        config.tls_path = Some(user_dir.path().display().to_string());
+        refresh(dir.path(), &config).unwrap();
+        assert!(!covers(&user_cert, "s3.example.com").unwrap());
+
+        config.tls_path = Some(issued.tls_path);
+        refresh(dir.path(), &config).unwrap();
+        assert!(covers(&cert_path, "s3.example.com").unwrap());
+        assert!(covers(&cert_path, "localhost").unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn overwritten_keys_become_private() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(KEY_FILE);
+        std::fs::write(&path, "old key").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_private(&path, b"new key").unwrap();
+        let metadata = std::fs::metadata(&path).unwrap();
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new key");
+    }
+
+    #[test]
+    fn exports_the_ca_certificate() {
+        let dir = tempfile::tempdir().unwrap();
+        let exported = dir.path().join("ca.pem");
+        assert!(matches!(
+            export_ca(dir.path(), &exported),
+            Err(Error::TlsCaMissing)
+        ));
+
+        generate(dir.path(), "127.0.0.1").unwrap();
+        export_ca(dir.path(), &exported).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&exported).unwrap(),
+            std::fs::read_to_string(ca_cert_path(dir.path())).unwrap()
+        );
+    }
+}
@@ -0,0 +1,466 @@
+use crate::error::{Error, Result};
+use crate::ports;
+use crate::process;
+use crate::volumes;
+use rustfs_launcher_shared::config::{RustFsConfig, DEFAULT_CREDENTIAL};
+use rustfs_launcher_shared::ipc::FieldError;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::RangeInclusive;
+
+// Credential length limits enforced by RustFS at startup
+pub const ACCESS_KEY_LEN: RangeInclusive<usize> = 3..=20;
+pub const SECRET_KEY_LEN: RangeInclusive<usize> = 8..=40;
+
+// Flags the launcher sets itself; passing them again through `extra_args` would conflict
+const MANAGED_FLAGS: &[&str] = &[
+    "--address",
+    "--access-key",
+    "--secret-key",
+    "--console-enable",
+    "--console-address",
+    "--tls-path",
+    "--region",
+    "--server-domains",
+    "--obs-endpoint",
+];
+
+// Variables the launcher sets itself when delivering credentials through the environment
+const MANAGED_ENV: &[&str] = &[process::ACCESS_KEY_ENV, process::SECRET_KEY_ENV];
+
+/// Checks every field and returns all problems found, in form order.
+pub fn validate(config: &RustFsConfig) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    check_volumes(config, &mut errors);
+    check_listeners(config, &mut errors);
+    check_credentials(config, &mut errors);
+    check_server_options(config, &mut errors);
+    check_extra_options(config, &mut errors);
+    errors
+}
+
+/// Like [`validate`], but fails with every problem folded into one error.
+pub fn check(config: &RustFsConfig) -> Result<()> {
+    let errors = validate(config);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidConfig(errors))
+    }
+}
+
+/// Reports ports that another process is already listening on.
+pub fn check_ports(config: &RustFsConfig) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    if let Err(err) = ports::check_available(&config.address()) {
+        errors.push(FieldError::new("port", err.to_string()));
+    }
+    if config.console_enable {
+        if let Err(err) = ports::check_available(&config.console_address()) {
+            errors.push(FieldError::new("console_port", err.to_string()));
+        }
+    }
+    errors
+}
+
+fn check_volumes(config: &RustFsConfig, errors: &mut Vec<FieldError>) {
+    match volumes::expand_all(&config.data_volumes) {
+        Ok(expanded) => {
+            for volume in expanded {
+                if let Err(err) = volumes::check_volume(&volume) {
+                    errors.push(FieldError::new("data_volumes", err.to_string()));
+                }
+            }
+        }
+        Err(err) => errors.push(FieldError::new("data_volumes", err.to_string())),
+    }
+}
+
+fn check_listeners(config: &RustFsConfig, errors: &mut Vec<FieldError>) {
+    if let Some(host) = &config.host {
+        if let Err(message) = parse_host(host) {
+            errors.push(FieldError::new("host", message));
+        }
+    }
+    if config.port == Some(0) {
+        errors.push(FieldError::new("port", "Port must be between 1 and 65535"));
+    }
+
+    if !config.console_enable {
+        return;
+    }
+    if let Some(host) = &config.console_host {
+        if let Err(message) = parse_host(host) {
+            errors.push(FieldError::new("console_host", message));
+        }
+    }
+    if config.console_port == Some(0) {
+        errors.push(FieldError::new(
+            "console_port",
+            "Console port must be between 1 and 65535",
+        ));
+    } else if config.console_address() == config.address() {
+        errors.push(FieldError::new(
+            "console_port",
+            format!(
+                "Console address {} must differ from the S3 address",
+                config.console_address()
+            ),
+        ));
+    }
+}
+
+fn check_credentials(config: &RustFsConfig, errors: &mut Vec<FieldError>) {
+    let exposed = config.is_exposed();
+
+    let keys = [
+        (
+            "access_key",
+            "Access key",
+            &config.access_key,
+            ACCESS_KEY_LEN,
+        ),
+        (
+            "secret_key",
+            "Secret key",
+            &config.secret_key,
+            SECRET_KEY_LEN,
+        ),
+    ];
+    for (field, label, value, range) in keys {
+        // A saved secret key was checked when it was entered
+        if field == "secret_key" && value.is_none() && config.secret_ref.is_some() {
+            continue;
+        }
+        let value = value.as_deref().unwrap_or(DEFAULT_CREDENTIAL);
+        let len = value.chars().count();
+        if !range.contains(&len) {
+            errors.push(FieldError::new(
+                field,
+                format!(
+                    "{} must be {} to {} characters long",
+                    label,
+                    range.start(),
+                    range.end()
+                ),
+            ));
+        } else if value == DEFAULT_CREDENTIAL && exposed {
+            errors.push(FieldError::new(
+                field,
+                format!(
+                    "{} must not be the default \"{}\" when listening on a non-loopback host",
+                    label, DEFAULT_CREDENTIAL
+                ),
+            ));
+        }
+    }
+}
+
+fn check_server_options(config: &RustFsConfig, errors: &mut Vec<FieldError>) {
+    if let Some(tls_path) = &config.tls_path {
+        if !std::path::Path::new(tls_path).is_dir() {
+            errors.push(FieldError::new(
+                "tls_path",
+                format!("TLS directory does not exist: {}", tls_path),
+            ));
+        }
+    }
+
+    if let Some(region) = &config.region {
+        let valid = !region.is_empty()
+            && region
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid {
+            errors.push(FieldError::new(
+                "region",
+                format!(
+                    "Region {:?} may only contain lowercase letters, digits and '-'",
+                    region
+                ),
+            ));
+        }
+    }
+
+    for domain in config.server_domains() {
+        if !is_valid_domain(domain) {
+            errors.push(FieldError::new(
+                "server_domains",
+                format!("{:?} is not a valid server domain", domain),
+            ));
+        }
+    }
+
+    if let Some(endpoint) = &config.obs_endpoint {
+        if !(endpoint.starts_with("http://") || endpoint.starts_with("https://")) {
+            errors.push(FieldError::new(
+                "obs_endpoint",
+                format!(
+                    "Observability endpoint {:?} must be an http:// or https:// URL",
+                    endpoint
+                ),
+            ));
+        }
+    }
+}
+
+fn check_extra_options(config: &RustFsConfig, errors: &mut Vec<FieldError>) {
+    for arg in &config.extra_args {
+        if arg.trim().is_empty() {
+            errors.push(FieldError::new("extra_args", "Arguments must not be empty"));
+        } else if arg.contains('\0') {
+            errors.push(FieldError::new(
+                "extra_args",
+                format!("{:?} contains a NUL byte", arg),
+            ));
+        } else {
+            let flag = arg.split('=').next().unwrap_or(arg);
+            if MANAGED_FLAGS.contains(&flag) {
+                errors.push(FieldError::new(
+                    "extra_args",
+                    format!(
+                        "{} is set by the launcher; use the matching field instead",
+                        flag
+                    ),
+                ));
+            }
+        }
+    }
+
+    for (name, value) in &config.env {
+        let mut chars = name.chars();
+        let valid_name = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_name {
+            errors.push(FieldError::new(
+                "env",
+                format!("{:?} is not a valid variable name", name),
+            ));
+        } else if MANAGED_ENV.contains(&name.as_str()) {
+            errors.push(FieldError::new(
+                "env",
+                format!(
+                    "{} is set by the launcher; use the matching field instead",
+                    name
+                ),
+            ));
+        } else if value.contains('\0') {
+            errors.push(FieldError::new(
+                "env",
+                format!("Value of {} contains a NUL byte", name),
+            ));
+        }
+    }
+}
+
+/// Parses a listen host, returning whether it is a loopback address.
+fn parse_host(host: &str) -> std::result::Result<bool, String> {
+    if let Some(inner) = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+        return inner
+            .parse::<Ipv6Addr>()
+            .map(|ip| ip.is_loopback())
+            .map_err(|_| format!("{:?} is not a valid IPv6 address", host));
+    }
+    if host.parse::<Ipv6Addr>().is_ok() {
+        return Err(format!(
+            "IPv6 addresses must be written in brackets, e.g. [{}]",
+            host
+        ));
+    }
+    if host.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return host
+            .parse::<Ipv4Addr>()
+            .map(|ip| ip.is_loopback())
+            .map_err(|_| format!("{:?} is not a valid IPv4 address", host));
+    }
+    if is_valid_hostname(host) {
+        return Ok(host.eq_ignore_ascii_case("localhost"));
+    }
+    Err(format!("{:?} is not a valid IP address or hostname", host))
+}
+
+/// Accepts `name[.name…][:port]` with DNS-style labels.
+fn is_valid_domain(domain: &str) -> bool {
+    let (host, port) = match domain.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (domain, None),
+    };
+    if port.is_some_and(|port| port.parse::<u16>().is_err()) {
+        return false;
+    }
+    is_valid_hostname(host)
+}
+
+fn is_valid_hostname(host: &str) -> bool {
+    !host.is_empty()
+        && host.len() <= 253
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(config: &RustFsConfig) -> Vec<String> {
+        validate(config)
+            .into_iter()
+            .map(|error| error.field)
+            .collect()
+    }
+
+    fn valid_config(dir: &std::path::Path) -> RustFsConfig {
+        RustFsConfig {
+            data_volumes: vec![dir.display().to_string()],
+            ..RustFsConfig::default()
+        }
+    }
+
+    #[test]
+    fn default_config_on_loopback_is_valid() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(validate(&valid_config(dir.path())).is_empty());
+    }
+
+    #[test]
+    fn reports_every_problem_at_once() {
+        let config = RustFsConfig {
+            data_volumes: vec!["/nonexistent/rustfs-volume".to_string()],
+            host: Some("not a host".to_string()),
+            port: Some(0),
+            access_key: Some("ab".to_string()),
+            secret_key: Some("short".to_string()),
+            ..RustFsConfig::default()
+        };
+        assert_eq!(
+            fields(&config),
+            ["data_volumes", "host", "port", "access_key", "secret_key"]
+        );
+    }
+
+    #[test]
+    fn rejects_default_credentials_on_exposed_hosts() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = valid_config(dir.path());
+        config.host = Some("0.0.0.0".to_string());
+        assert_eq!(fields(&config), ["access_key", "secret_key"]);
+
+        config.access_key = Some("launcher".to_string());
+        config.secret_key = Some("launcher-secret".to_string());
+        assert!(validate(&config).is_empty());
+
+        let mut config = valid_config(dir.path());
+        config.console_enable = true;
+        config.console_host = Some("192.168.1.10".to_string());
+        assert_eq!(fields(&config), ["access_key", "secret_key"]);
+    }
+
+    #[test]
+    fn rejects_managed_and_malformed_extra_args() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = valid_config(dir.path());
+        config.extra_args = vec!["--json".to_string(), "--buffer-size=4".to_string()];
+        assert!(validate(&config).is_empty());
+
+        for arg in [
+            "--address",
+            "--secret-key=s3cr3t",
+            "--tls-path=/tmp",
+            " ",
+            "--a\0b",
+        ] {
+            config.extra_args = vec![arg.to_string()];
+            assert_eq!(fields(&config), ["extra_args"], "{:?}", arg);
+        }
+        config.extra_args = vec!["--access-key=admin".to_string()];
+        assert!(validate(&config)[0]
+            .message
+            .starts_with("--access-key is set"));
+    }
+
+    #[test]
+    fn rejects_managed_and_malformed_env() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = valid_config(dir.path());
+        config
+            .env
+            .insert("RUSTFS_OBS_LOGGER_LEVEL".to_string(), "debug".to_string());
+        config.env.insert("_PRIVATE_1".to_string(), String::new());
+        assert!(validate(&config).is_empty());
+
+        for (name, value) in [
+            (process::SECRET_KEY_ENV, "s3cr3t"),
+            (process::ACCESS_KEY_ENV, "admin"),
+            ("1ST", "value"),
+            ("WITH-DASH", "value"),
+            ("", "value"),
+            ("NUL_VALUE", "a\0b"),
+        ] {
+            let mut config = valid_config(dir.path());
+            config.env.insert(name.to_string(), value.to_string());
+            assert_eq!(fields(&config), ["env"], "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn checks_server_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = valid_config(dir.path());
+        config.region = Some("us-east-1".to_string());
+        config.server_domains = vec!["s3.example.com".to_string(), "localhost:9000".to_string()];
+        config.obs_endpoint = Some("https://otel.example.com:4318".to_string());
+        config.tls_path = Some(dir.path().display().to_string());
+        assert!(validate(&config).is_empty());
+
+        let mut config = valid_config(dir.path());
+        config.region = Some("US_East".to_string());
+        config.server_domains = vec!["bad_domain".to_string(), "s3.example.com:http".to_string()];
+        config.obs_endpoint = Some("otel.example.com:4318".to_string());
+        config.tls_path = Some(dir.path().join("missing").display().to_string());
+        assert_eq!(
+            fields(&config),
+            [
+                "tls_path",
+                "region",
+                "server_domains",
+                "server_domains",
+                "obs_endpoint"
+            ]
+        );
+
+        config = valid_config(dir.path());
+        config.region = Some(String::new());
+        assert_eq!(fields(&config), ["region"]);
+    }
+
+    #[test]
+    fn validates_domains() {
+        assert!(is_valid_domain("s3.example.com"));
+        assert!(is_valid_domain("localhost"));
+        assert!(is_valid_domain("s3.example.com:9000"));
+        assert!(!is_valid_domain("s3.example.com:70000"));
+        assert!(!is_valid_domain("s3.example.com:"));
+        assert!(!is_valid_domain(":9000"));
+        assert!(!is_valid_domain("-s3.example.com"));
+        assert!(!is_valid_domain("s3..example.com"));
+        assert!(!is_valid_domain(&format!("{}.com", "a".repeat(64))));
+    }
+
+    #[test]
+    fn parses_hosts() {
+        assert_eq!(parse_host("127.0.0.1"), Ok(true));
+        assert_eq!(parse_host("localhost"), Ok(true));
+        assert_eq!(parse_host("[::1]"), Ok(true));
+        assert_eq!(parse_host("0.0.0.0"), Ok(false));
+        assert_eq!(parse_host("s3.example.com"), Ok(false));
+        assert!(parse_host("::1").is_err());
+        assert!(parse_host("300.1.1.1").is_err());
+        assert!(parse_host("bad_host").is_err());
+    }
+}
@@ -0,0 +1,275 @@
+//! Passphrase-protected file for saved secrets.
+//!
+//! The key is derived with Argon2id and every entry is sealed with XChaCha20-Poly1305
+//! under its own random nonce. Only the derived key is kept in memory once unlocked.
+
+use crate::error::{Error, Result};
+use crate::secrets::SecretStore;
+use crate::store::write_atomic;
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use lazy_static::lazy_static;
+use rustfs_launcher_shared::ipc::VaultStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use zeroize::Zeroizing;
+
+const VAULT_FILE: &str = "vault.json";
+const FORMAT_VERSION: u32 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+// Sealed when the vault is created so a wrong passphrase is caught at unlock
+const CHECK_PLAINTEXT: &[u8] = b"rustfs-launcher-vault";
+
+lazy_static! {
+    static ref UNLOCKED: Mutex<Option<Vault>> = Mutex::new(None);
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KdfParams {
+    salt: String,
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Sealed {
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VaultFile {
+    version: u32,
+    kdf: KdfParams,
+    check: Sealed,
+    #[serde(default)]
+    entries: BTreeMap<String, Sealed>,
+}
+
+/// An unlocked vault file.
+pub struct Vault {
+    path: PathBuf,
+    key: Zeroizing<[u8; 32]>,
+}
+
+impl Vault {
+    /// Opens the vault at `path` with `passphrase`, creating it if it does not exist.
+    pub fn open(path: &Path, passphrase: &str) -> Result<Self> {
+        if !path.exists() {
+            return Self::create(path, passphrase);
+        }
+
+        let file = read_file(path)?;
+        if file.version > FORMAT_VERSION {
+            return Err(Error::VaultCorrupt(format!(
+                "format version {} is newer than this launcher supports",
+                file.version
+            )));
+        }
+        let key = derive_key(passphrase, &file.kdf)?;
+        match open_sealed(&key, &file.check) {
+            Ok(check) if check == CHECK_PLAINTEXT => Ok(Self {
+                path: path.to_path_buf(),
+                key,
+            }),
+            _ => Err(Error::VaultPassphrase),
+        }
+    }
+
+    fn create(path: &Path, passphrase: &str) -> Result<Self> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let kdf = KdfParams {
+            salt: BASE64.encode(salt),
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+        };
+        let key = derive_key(passphrase, &kdf)?;
+        let file = VaultFile {
+            version: FORMAT_VERSION,
+            kdf,
+            check: seal(&key, CHECK_PLAINTEXT)?,
+            entries: BTreeMap::new(),
+        };
+        write_file(path, &file)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            key,
+        })
+    }
+
+    pub fn get(&self, id: &str) -> Result<String> {
+        let file = read_file(&self.path)?;
+        let sealed = file
+            .entries
+            .get(id)
+            .ok_or_else(|| Error::SecretNotFound(id.to_string()))?;
+        let plaintext = open_sealed(&self.key, sealed)?;
+        String::from_utf8(plaintext)
+            .map_err(|_| Error::VaultCorrupt(format!("entry {} is not valid UTF-8", id)))
+    }
+
+    pub fn set(&self, id: &str, secret: &str) -> Result<()> {
+        let mut file = read_file(&self.path)?;
+        file.entries
+            .insert(id.to_string(), seal(&self.key, secret.as_bytes())?);
+        write_file(&self.path, &file)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<()> {
+        let mut file = read_file(&self.path)?;
+        if file.entries.remove(id).is_some() {
+            write_file(&self.path, &file)?;
+        }
+        Ok(())
+    }
+}
+
+/// The session's unlocked vault, as a [`SecretStore`].
+pub struct VaultStore;
+
+impl VaultStore {
+    fn with<T>(f: impl FnOnce(&Vault) -> Result<T>) -> Result<T> {
+        match UNLOCKED.lock().unwrap().as_ref() {
+            Some(vault) => f(vault),
+            None => Err(Error::VaultLocked),
+        }
+    }
+}
+
+impl SecretStore for VaultStore {
+    fn get(&self, id: &str) -> Result<String> {
+        Self::with(|vault| vault.get(id))
+    }
+
+    fn set(&self, id: &str, secret: &str) -> Result<()> {
+        Self::with(|vault| vault.set(id, secret))
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        Self::with(|vault| vault.delete(id))
+    }
+}
+
+pub fn status(dir: &Path) -> VaultStatus {
+    VaultStatus {
+        exists: dir.join(VAULT_FILE).exists(),
+        unlocked: UNLOCKED.lock().unwrap().is_some(),
+    }
+}
+
+/// Unlocks the vault in `dir` for the rest of the session, creating it on first use.
+pub fn unlock(dir: &Path, passphrase: &str) -> Result<()> {
+    let vault = Vault::open(&dir.join(VAULT_FILE), passphrase)?;
+    *UNLOCKED.lock().unwrap() = Some(vault);
+    Ok(())
+}
+
+pub fn lock() {
+    *UNLOCKED.lock().unwrap() = None;
+}
+
+fn derive_key(passphrase: &str, kdf: &KdfParams) -> Result<Zeroizing<[u8; 32]>> {
+    let salt = decode(&kdf.salt)?;
+    let params = Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(32))
+        .map_err(|err| Error::VaultCorrupt(err.to_string()))?;
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salt, key.as_mut())
+        .map_err(|err| Error::VaultCorrupt(err.to_string()))?;
+    Ok(key)
+}
+
+fn seal(key: &[u8; 32], plaintext: &[u8]) -> Result<Sealed> {
+    let cipher = XChaCha20Poly1305::new(Key::from_slice(key));
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|err| Error::VaultCorrupt(err.to_string()))?;
+    Ok(Sealed {
+        nonce: BASE64.encode(nonce),
+        ciphertext: BASE64.encode(ciphertext),
+    })
+}
+
+fn open_sealed(key: &[u8; 32], sealed: &Sealed) -> Result<Vec<u8>> {
+    let nonce = decode(&sealed.nonce)?;
+    if nonce.len() != NONCE_LEN {
+        return Err(Error::VaultCorrupt("invalid nonce length".to_string()));
+    }
+    let ciphertext = decode(&sealed.ciphertext)?;
+    XChaCha20Poly1305::new(Key::from_slice(key))
+        .decrypt(XNonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|_| Error::VaultCorrupt("an entry failed to decrypt".to_string()))
+}
+
+fn decode(value: &str) -> Result<Vec<u8>> {
+    BASE64
+        .decode(value)
+        .map_err(|err| Error::VaultCorrupt(err.to_string()))
+}
+
+fn read_file(path: &Path) -> Result<VaultFile> {
+    let contents = std::fs::read_to_string(path)?;
+    serde_json::from_str(&contents).map_err(|err| Error::VaultCorrupt(err.to_string()))
+}
+
+fn write_file(path: &Path, file: &VaultFile) -> Result<()> {
+    write_atomic(path, &serde_json::to_vec_pretty(file)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secrets_round_trip_through_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(VAULT_FILE);
+        let vault = Vault::open(&path, "correct horse").unwrap();
+        vault.set("profile", "launcher-secret").unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("launcher-secret"));
+
+        let reopened = Vault::open(&path, "correct horse").unwrap();
+        assert_eq!(reopened.get("profile").unwrap(), "launcher-secret");
+        reopened.delete("profile").unwrap();
+        assert!(matches!(
+            reopened.get("profile"),
+            Err(Error::SecretNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_wrong_passphrase_and_tampering() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(VAULT_FILE);
+        Vault::open(&path, "correct horse")
+            .unwrap()
+            .set("profile", "launcher-secret")
+            .unwrap();
+        assert!(matches!(
+            Vault::open(&path, "battery staple"),
+            Err(Error::VaultPassphrase)
+        ));
+
+        let mut file = read_file(&path).unwrap();
+        let entry = file.entries.get_mut("profile").unwrap();
+        let mut ciphertext = decode(&entry.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        entry.ciphertext = BASE64.encode(ciphertext);
+        write_file(&path, &file).unwrap();
+        let vault = Vault::open(&path, "correct horse").unwrap();
+        assert!(matches!(vault.get("profile"), Err(Error::VaultCorrupt(_))));
+    }
+}
@@ -0,0 +1,165 @@
+use crate::error::{Error, Result};
+use std::collections::BTreeSet;
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+
+// Guards against typos like `{1...40000}` creating thousands of directories
+const MAX_VOLUMES: usize = 256;
+
+const WRITE_PROBE_FILE: &str = ".rustfs-launcher-write-test";
+
+/// Expands ellipsis patterns such as `/data/vol{1...4}` the way RustFS does.
+///
+/// Several ranges expand to their cartesian product and a zero-padded start
+/// (`{01...16}`) pads every number to the same width.
+pub fn expand(pattern: &str) -> Result<Vec<String>> {
+    let Some(open) = pattern.find('{') else {
+        return Ok(vec![pattern.to_string()]);
+    };
+    let invalid = || Error::InvalidVolumePattern(pattern.to_string());
+    let close = pattern[open..]
+        .find('}')
+        .map(|i| open + i)
+        .ok_or_else(invalid)?;
+
+    let (from, to) = pattern[open + 1..close]
+        .split_once("...")
+        .ok_or_else(invalid)?;
+    let width = if from.len() > 1 && from.starts_with('0') {
+        from.len()
+    } else {
+        0
+    };
+    let from: u32 = from.parse().map_err(|_| invalid())?;
+    let to: u32 = to.parse().map_err(|_| invalid())?;
+    if from > to || (to - from) as usize >= MAX_VOLUMES {
+        return Err(invalid());
+    }
+
+    let prefix = &pattern[..open];
+    let suffixes = expand(&pattern[close + 1..])?;
+    let mut expanded = Vec::new();
+    for n in from..=to {
+        for suffix in &suffixes {
+            expanded.push(format!("{}{:0width$}{}", prefix, n, suffix, width = width));
+        }
+    }
+    if expanded.len() > MAX_VOLUMES {
+        return Err(invalid());
+    }
+    Ok(expanded)
+}
+
+/// Expands every configured volume, rejecting an empty list and duplicates.
+pub fn expand_all(volumes: &[String]) -> Result<Vec<String>> {
+    let mut seen = BTreeSet::new();
+    let mut expanded = Vec::new();
+    for volume in volumes {
+        let volume = volume.trim();
+        if volume.is_empty() {
+            continue;
+        }
+        for path in expand(volume)? {
+            if !seen.insert(path.clone()) {
+                return Err(Error::DuplicateDataVolume(path));
+            }
+            expanded.push(path);
+        }
+    }
+    if expanded.is_empty() {
+        return Err(Error::DataVolumeRequired);
+    }
+    Ok(expanded)
+}
+
+/// Checks a single expanded volume by creating and removing a probe file.
+pub fn check_volume(volume: &str) -> Result<()> {
+    let path = Path::new(volume);
+    if !path.is_dir() {
+        return Err(Error::DataVolumeNotExist(volume.to_string()));
+    }
+
+    let probe = path.join(WRITE_PROBE_FILE);
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&probe)
+        .and_then(|_| fs::remove_file(&probe))
+        .map_err(|err| Error::DataVolumeNotWritable(volume.to_string(), err))
+}
+
+/// Creates the directories a pattern expands to and returns their paths.
+pub fn create(pattern: &str) -> Result<Vec<String>> {
+    let expanded = expand(pattern.trim())?;
+    for volume in &expanded {
+        fs::create_dir_all(volume)?;
+    }
+    Ok(expanded)
+}
+
+/// RustFS logs go next to the first volume so they stay out of the erasure set.
+pub fn logs_dir(volumes: &[String]) -> PathBuf {
+    let first = volumes
+        .iter()
+        .map(|volume| volume.trim())
+        .find(|volume| !volume.is_empty())
+        .and_then(|volume| expand(volume).ok())
+        .and_then(|expanded| expanded.into_iter().next());
+    match first.as_deref().map(Path::new).and_then(Path::parent) {
+        Some(parent) => parent.join("logs"),
+        None => PathBuf::from("logs"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_ranges_in_order() {
+        assert_eq!(
+            expand("/data/vol{1...3}").unwrap(),
+            ["/data/vol1", "/data/vol2", "/data/vol3"]
+        );
+        assert_eq!(
+            expand("/d{1...2}/v{08...09}").unwrap(),
+            ["/d1/v08", "/d1/v09", "/d2/v08", "/d2/v09"]
+        );
+        assert_eq!(expand("/data/plain").unwrap(), ["/data/plain"]);
+    }
+
+    #[test]
+    fn rejects_malformed_patterns() {
+        for pattern in [
+            "/data/vol{1..4}",
+            "/data/vol{4...1}",
+            "/data/vol{1...",
+            "/v{a...b}",
+        ] {
+            assert!(matches!(
+                expand(pattern),
+                Err(Error::InvalidVolumePattern(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn creates_and_checks_volumes() {
+        let dir = tempfile::tempdir().unwrap();
+        let pattern = format!("{}/vol{{1...4}}", dir.path().display());
+        assert_eq!(create(&pattern).unwrap().len(), 4);
+        for volume in expand_all(std::slice::from_ref(&pattern)).unwrap() {
+            check_volume(&volume).unwrap();
+        }
+        assert!(matches!(
+            check_volume(&format!("{}/vol5", dir.path().display())),
+            Err(Error::DataVolumeNotExist(_))
+        ));
+        assert!(matches!(
+            expand_all(&[pattern.clone(), format!("{}/vol2", dir.path().display())]),
+            Err(Error::DuplicateDataVolume(_))
+        ));
+        assert_eq!(logs_dir(&[pattern]), dir.path().join("logs"));
+    }
+}
@@ -1,8 +1,19 @@
+use crate::ipc::{self, to_js, IpcResult};
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
-use serde::{Deserialize, Serialize};
+use rustfs_launcher_shared::config::{
+    CredentialDelivery, RestartPolicy, RustFsConfig, SecretBackend, DEFAULT_CREDENTIAL,
+};
+use rustfs_launcher_shared::events;
+use rustfs_launcher_shared::health::{HealthStatus, HealthUpdate, ProbeResult};
+use rustfs_launcher_shared::ipc::{
+    CommandResponse, ErrorCode, FieldError, ProfileList, VaultStatus,
+};
+use rustfs_launcher_shared::log::LogEntry;
+use rustfs_launcher_shared::redact::redact;
+use rustfs_launcher_shared::status::RustFsStatus;
 use serde_json;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
@@ -10,10 +21,26 @@ use wasm_bindgen_futures::spawn_local;
 // Import CSS styles
 const LOGS_CSS: &str = include_str!("logs.css");
 
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke)]
-    async fn tauri_invoke(cmd: &str, args: JsValue) -> JsValue;
+fn optional(value: String) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn prompt(message: &str, default: &str) -> Option<String> {
+    web_sys::window()
+        .and_then(|w| w.prompt_with_message_and_default(message, default).ok())
+        .flatten()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn confirm(message: &str) -> bool {
+    web_sys::window()
+        .and_then(|w| w.confirm_with_message(message).ok())
+        .unwrap_or(false)
 }
 
 // Helper function to check if we're in Tauri environment
@@ -29,41 +56,132 @@ extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
     async fn open(options: JsValue) -> JsValue;
 
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"], js_name = save)]
+    async fn save_dialog(options: JsValue) -> JsValue;
+
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum LogType {
+    App,
+    RustFS,
+    Health,
+}
+
+fn health_badge_class(update: &HealthUpdate) -> &'static str {
+    match update.status {
+        HealthStatus::Healthy => "status-badge running",
+        HealthStatus::Degraded => "status-badge starting",
+        HealthStatus::Unreachable => "status-badge crashed",
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct RustFsConfig {
-    data_path: String,
-    port: Option<u16>,
-    host: Option<String>,
-    access_key: Option<String>,
-    secret_key: Option<String>,
-    console_enable: bool,
+fn health_label(update: &HealthUpdate) -> String {
+    match (update.status, update.latency_ms) {
+        (HealthStatus::Healthy, Some(ms)) => format!("Healthy ({}ms)", ms),
+        (HealthStatus::Healthy, None) => "Healthy".to_string(),
+        (HealthStatus::Degraded, _) => {
+            format!("Degraded ({} failed probes)", update.consecutive_failures)
+        }
+        (HealthStatus::Unreachable, _) => "Unreachable".to_string(),
+    }
 }
 
-impl Default for RustFsConfig {
-    fn default() -> Self {
-        Self {
-            data_path: String::new(),
-            port: Some(9000),
-            host: Some("127.0.0.1".to_string()),
-            access_key: Some("rustfsadmin".to_string()),
-            secret_key: Some("rustfsadmin".to_string()),
-            console_enable: false,
+fn probe_log_line(result: &ProbeResult) -> String {
+    // RFC 3339 timestamps carry the wall-clock time at offset 11..19
+    let time = result.timestamp.get(11..19).unwrap_or(&result.timestamp);
+    match (result.ok, result.latency_ms, &result.error) {
+        (true, Some(ms), _) => {
+            format!(
+                "[{}] {} {} OK {}ms",
+                time, result.target, result.address, ms
+            )
         }
+        (_, _, Some(error)) => {
+            format!(
+                "[{}] {} {} FAILED: {}",
+                time, result.target, result.address, error
+            )
+        }
+        _ => format!("[{}] {} {} FAILED", time, result.target, result.address),
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-enum LogType {
-    App,
-    RustFS,
+const HEALTH_HISTORY_LIMIT: usize = 100;
+
+async fn fetch_health_history(
+    writer: WriteSignal<VecDeque<String>>,
+    set_status: WriteSignal<String>,
+) {
+    match ipc::get_health(HEALTH_HISTORY_LIMIT).await {
+        Ok(report) => writer.set(report.results.iter().map(probe_log_line).collect()),
+        Err(err) => set_status.set(format!("Failed to load health history: {}", err)),
+    }
+}
+
+fn status_badge_class(status: &RustFsStatus) -> &'static str {
+    match status {
+        RustFsStatus::Stopped | RustFsStatus::Exited { .. } => "status-badge stopped",
+        RustFsStatus::Starting | RustFsStatus::Restarting { .. } => "status-badge starting",
+        RustFsStatus::Running { .. } => "status-badge running",
+        RustFsStatus::Crashed { .. } | RustFsStatus::GaveUp { .. } => "status-badge crashed",
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct CommandResponse {
-    success: bool,
-    message: String,
+fn status_label(status: &RustFsStatus) -> String {
+    match status {
+        RustFsStatus::Stopped => "Stopped".to_string(),
+        RustFsStatus::Starting => "Starting".to_string(),
+        RustFsStatus::Running { pid } => format!("Running (PID {})", pid),
+        RustFsStatus::Exited { code: Some(code) } => format!("Exited (code {})", code),
+        RustFsStatus::Exited { code: None } => "Exited".to_string(),
+        RustFsStatus::Crashed {
+            signal: Some(signal),
+            ..
+        } => format!("Crashed (signal {})", signal),
+        RustFsStatus::Crashed {
+            code: Some(code), ..
+        } => format!("Crashed (code {})", code),
+        RustFsStatus::Crashed { .. } => "Crashed".to_string(),
+        RustFsStatus::Restarting {
+            attempt,
+            delay_secs,
+        } => format!("Restarting in {}s (attempt {})", delay_secs, attempt),
+        RustFsStatus::GaveUp { restarts } => {
+            format!("Gave up after {} restarts", restarts)
+        }
+    }
+}
+
+/// Profile operations; `run` returns the config to show afterwards, if it changed.
+enum ProfileCommand {
+    Load(String),
+    Create(String, Box<RustFsConfig>),
+    Rename(String, String),
+    Duplicate(String, String),
+    Delete(String),
+}
+
+impl ProfileCommand {
+    async fn run(self) -> IpcResult<Option<RustFsConfig>> {
+        match self {
+            ProfileCommand::Load(name) => ipc::load_profile(&name).await.map(Some),
+            ProfileCommand::Create(name, config) => {
+                ipc::create_profile(&name, &config).await.map(|()| None)
+            }
+            ProfileCommand::Rename(name, new_name) => {
+                ipc::rename_profile(&name, &new_name).await.map(|()| None)
+            }
+            ProfileCommand::Duplicate(name, new_name) => ipc::duplicate_profile(&name, &new_name)
+                .await
+                .map(|()| None),
+            ProfileCommand::Delete(name) => {
+                ipc::delete_profile(&name).await?;
+                // Show whichever profile the backend fell back to
+                ipc::load_config().await
+            }
+        }
+    }
 }
 
 const APP_LOG_CAPACITY: usize = 100;
@@ -71,36 +189,466 @@ const RUSTFS_LOG_CAPACITY: usize = 1000;
 
 fn push_log(writer: WriteSignal<VecDeque<String>>, msg: String, capacity: usize) {
     writer.update(|logs| {
-        logs.push_back(msg);
+        logs.push_back(redact(&msg));
         if logs.len() > capacity {
             logs.pop_front();
         }
     });
 }
 
+async fn fetch_profiles(writer: WriteSignal<ProfileList>, set_status: WriteSignal<String>) {
+    match ipc::list_profiles().await {
+        Ok(list) => writer.set(list),
+        Err(err) => set_status.set(format!("Failed to load profiles: {}", err)),
+    }
+}
+
+async fn persist_config(config: RustFsConfig, set_app_logs: WriteSignal<VecDeque<String>>) {
+    let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+    match ipc::save_config(&config).await {
+        Ok(_) => push_log(
+            set_app_logs,
+            format!("[{}] Configuration saved", now),
+            APP_LOG_CAPACITY,
+        ),
+        Err(err) => push_log(
+            set_app_logs,
+            format!("[{}] Failed to save configuration: {}", now, err),
+            APP_LOG_CAPACITY,
+        ),
+    }
+}
+
+async fn restart_with_config(
+    config: RustFsConfig,
+    set_status: WriteSignal<String>,
+    set_app_logs: WriteSignal<VecDeque<String>>,
+) {
+    let result = ipc::restart_rustfs(&config).await;
+    let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+
+    match result {
+        Ok(CommandResponse {
+            message, address, ..
+        }) => {
+            set_status.set(match address {
+                Some(address) => format!("RustFS restarted successfully on {}!", address),
+                None => "RustFS restarted successfully!".to_string(),
+            });
+            push_log(
+                set_app_logs,
+                format!("[{}] Restart result: {}", now, message),
+                APP_LOG_CAPACITY,
+            );
+        }
+        Err(err) => {
+            set_status.set(format!("Restart failed: {}", err));
+            push_log(
+                set_app_logs,
+                format!("[{}] Restart failed: {}", now, err),
+                APP_LOG_CAPACITY,
+            );
+        }
+    }
+}
+
 #[component]
 pub fn App() -> impl IntoView {
-    let (config, set_config) = signal(RustFsConfig::default());
+    // Start with one empty volume row to type into
+    let (config, set_config) = signal(RustFsConfig {
+        data_volumes: vec![String::new()],
+        ..RustFsConfig::default()
+    });
+    // Rows of the environment editor, kept separately so half-typed names keep their place
+    let (env_rows, set_env_rows) = signal(Vec::<(String, String)>::new());
+    let (field_errors, set_field_errors) = signal(Vec::<FieldError>::new());
+    let field_error = move |field: &'static str| {
+        move || {
+            field_errors
+                .get()
+                .into_iter()
+                .filter(|error| error.field == field)
+                .map(|error| view! { <p class="field-error">{error.message}</p> })
+                .collect_view()
+        }
+    };
+    let sync_env = move |rows: Vec<(String, String)>| {
+        let env = rows
+            .iter()
+            .filter(|(name, _)| !name.is_empty())
+            .cloned()
+            .collect::<BTreeMap<_, _>>();
+        set_config.update(|c| c.env = env);
+        set_env_rows.set(rows);
+    };
+    let load_into_form = move |mut loaded: RustFsConfig| {
+        set_env_rows.set(loaded.env.clone().into_iter().collect());
+        set_field_errors.set(Vec::new());
+        if loaded.data_volumes.is_empty() {
+            loaded.data_volumes.push(String::new());
+        }
+        set_config.set(loaded);
+    };
     let (status, set_status) = signal(String::new());
+    // Runs backend validation, showing problems under their inputs; returns whether to proceed
+    let check_fields = move |config: RustFsConfig| async move {
+        match ipc::validate_config(&config).await {
+            Ok(errors) if !errors.is_empty() => {
+                set_status.set(format!(
+                    "Fix {} problem(s) in the configuration first",
+                    errors.len()
+                ));
+                set_field_errors.set(errors);
+                false
+            }
+            Ok(_) => {
+                set_field_errors.set(Vec::new());
+                true
+            }
+            Err(err) => {
+                set_status.set(format!("Failed to validate configuration: {}", err));
+                false
+            }
+        }
+    };
     let (is_running, set_is_running) = signal(false);
+    let (rustfs_status, set_rustfs_status) = signal(RustFsStatus::Stopped);
     let (show_secret, set_show_secret) = signal(false);
+    let (volume_base, set_volume_base) = signal(String::new());
+    let (volume_count, set_volume_count) = signal(4u32);
+    let (vault, set_vault) = signal(VaultStatus::default());
+    let (vault_passphrase, set_vault_passphrase) = signal(String::new());
+    let (ca_fingerprint, set_ca_fingerprint) = signal(Option::<String>::None);
+    // The vault matters when new secrets go there or the saved one already lives there
+    let uses_vault = move || {
+        let config = config.get();
+        config.secret_backend == SecretBackend::Vault
+            || config
+                .secret_ref
+                .is_some_and(|secret_ref| secret_ref.backend == SecretBackend::Vault)
+    };
     let (app_logs, set_app_logs) = signal(VecDeque::<String>::new());
     let (rustfs_logs, set_rustfs_logs) = signal(VecDeque::<String>::new());
     let (current_log_type, set_current_log_type) = signal(LogType::App);
+    let (profiles, set_profiles) = signal(ProfileList::default());
+    let (health, set_health) = signal(Option::<HealthUpdate>::None);
+    let (health_history, set_health_history) = signal(VecDeque::<String>::new());
     let logs_ref = NodeRef::<leptos::html::Div>::new();
 
-    let select_folder = move |_| {
+    // Runs a profile command, reports failures in the status area, and refreshes the list
+    let run_profile_command = move |command: ProfileCommand, done: String| {
+        spawn_local(async move {
+            match command.run().await {
+                Ok(loaded) => {
+                    if let Some(loaded) = loaded {
+                        load_into_form(loaded);
+                    }
+                    set_status.set(done);
+                }
+                Err(err) => set_status.set(format!("Profile error: {}", err)),
+            }
+            fetch_profiles(set_profiles, set_status).await;
+        });
+    };
+
+    let select_profile = move |ev| {
+        let name = event_target_value(&ev);
+        let done = format!("Loaded profile \"{}\"", name);
+        run_profile_command(ProfileCommand::Load(name), done);
+    };
+
+    let new_profile = move |_| {
+        if let Some(name) = prompt("Name for the new profile:", "") {
+            let done = format!("Created profile \"{}\"", name);
+            run_profile_command(
+                ProfileCommand::Create(name, Box::new(config.get_untracked())),
+                done,
+            );
+        }
+    };
+
+    let rename_profile = move |_| {
+        let Some(current) = profiles.get_untracked().last_used else {
+            return;
+        };
+        if let Some(new_name) = prompt("Rename profile to:", &current) {
+            let done = format!("Renamed profile to \"{}\"", new_name);
+            run_profile_command(ProfileCommand::Rename(current, new_name), done);
+        }
+    };
+
+    let duplicate_profile = move |_| {
+        let Some(current) = profiles.get_untracked().last_used else {
+            return;
+        };
+        if let Some(new_name) = prompt("Name for the copy:", &format!("{} copy", current)) {
+            let done = format!("Duplicated profile as \"{}\"", new_name);
+            run_profile_command(ProfileCommand::Duplicate(current, new_name), done);
+        }
+    };
+
+    let delete_profile = move |_| {
+        let Some(current) = profiles.get_untracked().last_used else {
+            return;
+        };
+        if confirm(&format!("Delete profile \"{}\"?", current)) {
+            let done = format!("Deleted profile \"{}\"", current);
+            run_profile_command(ProfileCommand::Delete(current), done);
+        }
+    };
+
+    let export_config = move |_| {
+        spawn_local(async move {
+            let options = to_js(&serde_json::json!({
+                "title": "Export RustFS Configuration",
+                "defaultPath": "rustfs-launcher.toml",
+                "filters": [{ "name": "TOML", "extensions": ["toml"] }]
+            }));
+            let Some(path) = save_dialog(options).await.as_string() else {
+                return;
+            };
+
+            let include_secrets = confirm(
+                "Include the secret key in the exported file?\n\nChoose Cancel to leave it out.",
+            );
+            match ipc::export_config(&path, &config.get_untracked(), include_secrets).await {
+                Ok(()) => set_status.set(format!("Configuration exported to {}", path)),
+                Err(err) => set_status.set(format!("Export failed: {}", err)),
+            }
+        });
+    };
+
+    let import_config = move |_| {
+        spawn_local(async move {
+            let options = to_js(&serde_json::json!({
+                "title": "Import RustFS Configuration",
+                "filters": [{ "name": "TOML", "extensions": ["toml"] }]
+            }));
+            let Some(path) = open(options).await.as_string() else {
+                return;
+            };
+
+            match ipc::import_config(&path).await {
+                Ok(imported) => {
+                    let missing_secret = imported.secret_key.is_none();
+                    load_into_form(imported);
+                    if missing_secret {
+                        set_status.set(format!(
+                            "Configuration imported from {}; enter its secret key before launching",
+                            path
+                        ));
+                    } else {
+                        set_status.set(format!("Configuration imported from {}", path));
+                    }
+                }
+                Err(err) => set_status.set(format!("Import failed: {}", err)),
+            }
+        });
+    };
+
+    let select_binary = move |_| {
+        spawn_local(async move {
+            let options = to_js(&serde_json::json!({
+                "directory": false,
+                "title": "Select RustFS Binary"
+            }));
+
+            if let Some(result) = open(options).await.as_string() {
+                if !result.is_empty() {
+                    set_config.update(|c| c.binary_path = Some(result));
+                }
+            }
+        });
+    };
+
+    let select_tls_folder = move |_| {
+        spawn_local(async move {
+            let options = to_js(&serde_json::json!({
+                "directory": true,
+                "title": "Select TLS Certificate Directory"
+            }));
+
+            if let Some(result) = open(options).await.as_string() {
+                if !result.is_empty() {
+                    set_config.update(|c| c.tls_path = Some(result));
+                }
+            }
+        });
+    };
+
+    let generate_certificate = move |_| {
+        spawn_local(async move {
+            if !is_tauri() {
+                return;
+            }
+
+            let host = config
+                .get_untracked()
+                .host
+                .unwrap_or_else(|| "127.0.0.1".to_string());
+            match ipc::generate_tls_certificate(&host).await {
+                Ok(info) => {
+                    set_config.update(|c| c.tls_path = Some(info.tls_path.clone()));
+                    set_ca_fingerprint.set(Some(info.ca_fingerprint));
+                    set_status.set(format!(
+                        "TLS certificate for {} written to {}",
+                        info.hosts.join(", "),
+                        info.tls_path
+                    ));
+                }
+                Err(err) => set_status.set(format!("Failed to generate certificate: {}", err)),
+            }
+        });
+    };
+
+    let export_ca = move |_| {
+        spawn_local(async move {
+            let options = to_js(&serde_json::json!({
+                "title": "Export TLS CA Certificate",
+                "defaultPath": "rustfs-launcher-ca.pem",
+                "filters": [{ "name": "PEM", "extensions": ["pem", "crt"] }]
+            }));
+            let Some(path) = save_dialog(options).await.as_string() else {
+                return;
+            };
+
+            match ipc::export_tls_ca(&path).await {
+                Ok(()) => set_status.set(format!("CA certificate exported to {}", path)),
+                Err(err) => set_status.set(format!("Export failed: {}", err)),
+            }
+        });
+    };
+
+    let select_folder = move |index: usize| {
         spawn_local(async move {
-            let options = serde_wasm_bindgen::to_value(&serde_json::json!({
+            let options = to_js(&serde_json::json!({
                 "directory": true,
                 "title": "Select RustFS Data Directory"
-            }))
-            .unwrap();
+            }));
+
+            if let Some(result) = open(options).await.as_string() {
+                if !result.is_empty() {
+                    set_config.update(|c| {
+                        if let Some(volume) = c.data_volumes.get_mut(index) {
+                            *volume = result;
+                        }
+                    });
+                }
+            }
+        });
+    };
+
+    let select_volume_base = move |_| {
+        spawn_local(async move {
+            let options = to_js(&serde_json::json!({
+                "directory": true,
+                "title": "Select Parent Directory for Volumes"
+            }));
 
             if let Some(result) = open(options).await.as_string() {
                 if !result.is_empty() {
-                    set_config.update(|c| c.data_path = result);
+                    set_volume_base.set(result);
+                }
+            }
+        });
+    };
+
+    // Fills both keys from the backend's CSPRNG and reveals them so they can be copied
+    let generate_keys = move || {
+        spawn_local(async move {
+            match ipc::generate_credentials().await {
+                Ok(credentials) => {
+                    set_config.update(|c| {
+                        c.access_key = Some(credentials.access_key);
+                        c.secret_key = Some(credentials.secret_key);
+                    });
+                    set_show_secret.set(true);
+                    set_status.set(
+                        "Generated new keys; they are saved with the configuration".to_string(),
+                    );
+                }
+                Err(err) => set_status.set(format!("Failed to generate keys: {}", err)),
+            }
+        });
+    };
+
+    let unlock_vault = move |_| {
+        spawn_local(async move {
+            let passphrase = vault_passphrase.get_untracked();
+            if passphrase.is_empty() {
+                set_status.set("Enter the vault passphrase".to_string());
+                return;
+            }
+            let creating = !vault.get_untracked().exists;
+            match ipc::unlock_vault(&passphrase).await {
+                Ok(()) => {
+                    set_vault_passphrase.set(String::new());
+                    set_status.set(if creating {
+                        "Secret vault created".to_string()
+                    } else {
+                        "Secret vault unlocked".to_string()
+                    });
+                }
+                Err(err) => set_status.set(format!("Failed to unlock vault: {}", err)),
+            }
+            if let Ok(status) = ipc::vault_status().await {
+                set_vault.set(status);
+            }
+        });
+    };
+
+    let lock_vault = move |_| {
+        spawn_local(async move {
+            if ipc::lock_vault().await.is_ok() {
+                set_vault.update(|status| status.unlocked = false);
+                set_status.set("Secret vault locked".to_string());
+            }
+        });
+    };
+
+    let create_volumes = move |_| {
+        spawn_local(async move {
+            if !is_tauri() {
+                return;
+            }
+
+            let base = volume_base.get_untracked();
+            let base = base.trim_end_matches(['/', '\\']);
+            if base.is_empty() {
+                set_status.set("Choose a parent directory for the volumes".to_string());
+                return;
+            }
+            let pattern = match volume_count.get_untracked() {
+                0 | 1 => format!("{}/vol1", base),
+                count => format!("{}/vol{{1...{}}}", base, count),
+            };
+            match ipc::create_data_volumes(&pattern).await {
+                Ok(created) => {
+                    set_config.update(|c| {
+                        c.data_volumes.retain(|volume| !volume.trim().is_empty());
+                        c.data_volumes.push(pattern.clone());
+                    });
+                    set_status.set(format!("Created {} volume(s): {}", created.len(), pattern));
+                }
+                Err(err) => set_status.set(format!("Failed to create volumes: {}", err)),
+            }
+        });
+    };
+
+    let suggest_port = move |_| {
+        spawn_local(async move {
+            if !is_tauri() {
+                return;
+            }
+
+            let current_config = config.get_untracked();
+            let host = current_config.host.as_deref().unwrap_or("127.0.0.1");
+            match ipc::find_free_port(host, current_config.port.unwrap_or(9000)).await {
+                Ok(port) => {
+                    set_config.update(|c| c.port = Some(port));
+                    set_status.set(format!("Port {} is free", port));
                 }
+                Err(err) => set_status.set(format!("No free port found: {}", err)),
             }
         });
     };
@@ -125,9 +673,6 @@ pub fn App() -> impl IntoView {
             APP_LOG_CAPACITY,
         );
 
-        const APP_LOG_EVENT: &str = "app-log";
-        const RUSTFS_LOG_EVENT: &str = "rustfs-log";
-
         fn create_log_listener(
             logs_signal: WriteSignal<VecDeque<String>>,
             max_logs: usize,
@@ -135,8 +680,8 @@ pub fn App() -> impl IntoView {
         ) -> Closure<dyn FnMut(JsValue)> {
             Closure::wrap(Box::new(move |event: JsValue| {
                 if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
-                    if let Some(log) = payload.as_string() {
-                        push_log(logs_signal, log, max_logs);
+                    if let Ok(entry) = serde_wasm_bindgen::from_value::<LogEntry>(payload) {
+                        push_log(logs_signal, entry.to_string(), max_logs);
                         if let Some(element) = logs_ref.get() {
                             element.scroll_to_with_x_and_y(0.0, f64::MAX);
                         }
@@ -154,6 +699,23 @@ pub fn App() -> impl IntoView {
                 logs_ref_clone.clone(),
             );
 
+            let status_listener = Closure::wrap(Box::new(move |event: JsValue| {
+                if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
+                    if let Ok(status) = serde_wasm_bindgen::from_value::<RustFsStatus>(payload) {
+                        set_rustfs_status.set(status);
+                    }
+                }
+            }) as Box<dyn FnMut(JsValue)>);
+
+            let health_listener = Closure::wrap(Box::new(move |event: JsValue| {
+                if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
+                    if let Ok(update) = serde_wasm_bindgen::from_value::<HealthUpdate>(payload) {
+                        set_health.set(Some(update));
+                        spawn_local(fetch_health_history(set_health_history, set_status));
+                    }
+                }
+            }) as Box<dyn FnMut(JsValue)>);
+
             if let Ok(tauri) = js_sys::Reflect::get(&window, &"__TAURI__".into()) {
                 if let Ok(event) = js_sys::Reflect::get(&tauri, &"event".into()) {
                     if let Ok(listen) = js_sys::Reflect::get(&event, &"listen".into()) {
@@ -161,31 +723,66 @@ pub fn App() -> impl IntoView {
 
                         let _ = listen_fn.call2(
                             &event,
-                            &APP_LOG_EVENT.into(),
+                            &events::APP_LOG.into(),
                             app_listener.as_ref().unchecked_ref(),
                         );
                         let _ = listen_fn.call2(
                             &event,
-                            &RUSTFS_LOG_EVENT.into(),
+                            &events::RUSTFS_LOG.into(),
                             rustfs_listener.as_ref().unchecked_ref(),
                         );
+                        let _ = listen_fn.call2(
+                            &event,
+                            &events::RUSTFS_STATUS.into(),
+                            status_listener.as_ref().unchecked_ref(),
+                        );
+                        let _ = listen_fn.call2(
+                            &event,
+                            &events::RUSTFS_HEALTH.into(),
+                            health_listener.as_ref().unchecked_ref(),
+                        );
                     }
                 }
             }
 
             app_listener.forget();
             rustfs_listener.forget();
+            status_listener.forget();
+            health_listener.forget();
+        }
+
+        match ipc::load_config().await {
+            Ok(Some(saved)) => load_into_form(saved),
+            // First run: nothing saved yet, so the form still holds the public defaults
+            Ok(None) => {
+                if confirm(&format!(
+                    "RustFS is set up with the well-known default keys \"{0}\" / \"{0}\".\n\nGenerate random access and secret keys instead?",
+                    DEFAULT_CREDENTIAL
+                )) {
+                    generate_keys();
+                }
+            }
+            Err(err) => set_status.set(format!("Failed to load configuration: {}", err)),
+        }
+        fetch_profiles(set_profiles, set_status).await;
+        if let Ok(status) = ipc::vault_status().await {
+            set_vault.set(status);
+        }
+        if let Ok(fingerprint) = ipc::tls_ca_fingerprint().await {
+            set_ca_fingerprint.set(fingerprint);
+        }
+
+        if let Ok(status) = ipc::get_rustfs_status().await {
+            set_rustfs_status.set(status);
         }
 
         // Fetch initial logs
-        let app_logs_value = tauri_invoke("get_app_logs", js_sys::Object::new().into()).await;
-        if let Ok(logs_vec) = serde_wasm_bindgen::from_value::<Vec<String>>(app_logs_value) {
-            app_log_writer.set(logs_vec.into_iter().collect());
+        if let Ok(entries) = ipc::get_app_logs().await {
+            app_log_writer.set(entries.iter().map(LogEntry::to_string).collect());
         }
 
-        let rustfs_logs_value = tauri_invoke("get_rustfs_logs", js_sys::Object::new().into()).await;
-        if let Ok(logs_vec) = serde_wasm_bindgen::from_value::<Vec<String>>(rustfs_logs_value) {
-            rustfs_log_writer.set(logs_vec.into_iter().collect());
+        if let Ok(entries) = ipc::get_rustfs_logs().await {
+            rustfs_log_writer.set(entries.iter().map(LogEntry::to_string).collect());
         }
     });
 
@@ -220,11 +817,15 @@ pub fn App() -> impl IntoView {
             }
 
             let current_config = config.get_untracked();
+            if !check_fields(current_config.clone()).await {
+                set_is_running.set(false);
+                return;
+            }
 
             // 添加详细日志
             leptos::logging::log!(
-                "Starting RustFS with config: data_path={}, port={:?}, host={:?}",
-                current_config.data_path,
+                "Starting RustFS with config: data_volumes={:?}, port={:?}, host={:?}",
+                current_config.data_volumes,
                 current_config.port,
                 current_config.host
             );
@@ -232,59 +833,140 @@ pub fn App() -> impl IntoView {
             let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
             push_log(
                 set_app_logs,
-                format!("[{}] Calling tauri_invoke with command: launch_rustfs", now),
+                format!("[{}] Calling launch_rustfs", now),
                 APP_LOG_CAPACITY,
             );
 
-            // Create args object with config parameter
-            let args = js_sys::Object::new();
-            let config_js = serde_wasm_bindgen::to_value(&current_config).unwrap();
-            js_sys::Reflect::set(&args, &"config".into(), &config_js).unwrap();
-
-            let result_value = tauri_invoke("launch_rustfs", args.into()).await;
-            let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
-            push_log(
-                set_app_logs,
-                format!("[{}] Invoke result: {:?}", now, result_value),
-                APP_LOG_CAPACITY,
-            );
-
-            match serde_wasm_bindgen::from_value::<CommandResponse>(result_value) {
-                Ok(CommandResponse { success, message }) => {
+            let response = match ipc::launch_rustfs(&current_config).await {
+                Ok(response) => response,
+                Err(err) => {
+                    let message = err.to_string();
                     let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
                     push_log(
                         set_app_logs,
-                        format!("[{}] Result message: {}", now, message),
+                        format!("[{}] Launch failed: {}", now, message),
                         APP_LOG_CAPACITY,
                     );
 
-                    if success {
-                        set_status.set("RustFS launched successfully!".to_string());
-                        let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
-                        push_log(
-                            set_app_logs,
-                            format!("[{}] Launch successful!", now),
-                            APP_LOG_CAPACITY,
-                        );
+                    if err.code() == Some(ErrorCode::AlreadyRunning) {
+                        let restart = confirm(&format!(
+                            "{}.\n\nRestart it with the current settings?",
+                            message
+                        ));
+                        if restart {
+                            set_status.set("Restarting RustFS...".to_string());
+                            restart_with_config(current_config, set_status, set_app_logs).await;
+                        } else {
+                            set_status.set(message);
+                        }
                     } else {
-                        set_status.set(format!("Launch result: {}", message));
-                        let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
-                        push_log(
-                            set_app_logs,
-                            format!("[{}] Launch result: {}", now, message),
-                            APP_LOG_CAPACITY,
-                        );
+                        set_status.set(format!("Launch failed: {}", message));
                     }
+                    set_is_running.set(false);
+                    return;
+                }
+            };
+            let CommandResponse {
+                success,
+                message,
+                address,
+            } = response;
+            let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+            push_log(
+                set_app_logs,
+                format!("[{}] Result message: {}", now, message),
+                APP_LOG_CAPACITY,
+            );
+
+            if success {
+                set_status.set(match address {
+                    Some(address) => {
+                        format!("RustFS launched successfully on {}!", address)
+                    }
+                    None => "RustFS launched successfully!".to_string(),
+                });
+                let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+                push_log(
+                    set_app_logs,
+                    format!("[{}] Launch successful!", now),
+                    APP_LOG_CAPACITY,
+                );
+                persist_config(current_config, set_app_logs).await;
+                fetch_profiles(set_profiles, set_status).await;
+            } else {
+                set_status.set(format!("Launch result: {}", message));
+                let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+                push_log(
+                    set_app_logs,
+                    format!("[{}] Launch result: {}", now, message),
+                    APP_LOG_CAPACITY,
+                );
+            }
+            set_is_running.set(false);
+        });
+    };
+
+    let stop_rustfs = move |_| {
+        set_status.set("Stopping RustFS...".to_string());
+
+        let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+        push_log(
+            set_app_logs,
+            format!("[{}] Stop button clicked", now),
+            APP_LOG_CAPACITY,
+        );
+
+        spawn_local(async move {
+            if !is_tauri() {
+                set_status.set("Error: Not running in Tauri environment".to_string());
+                return;
+            }
+
+            let result = ipc::stop_rustfs().await;
+            let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+
+            match result {
+                Ok(CommandResponse { message, .. }) => {
+                    set_status.set(message.clone());
+                    push_log(
+                        set_app_logs,
+                        format!("[{}] Stop result: {}", now, message),
+                        APP_LOG_CAPACITY,
+                    );
+                }
+                Err(err) => {
+                    set_status.set(format!("Stop failed: {}", err));
+                    push_log(
+                        set_app_logs,
+                        format!("[{}] Stop failed: {}", now, err),
+                        APP_LOG_CAPACITY,
+                    );
                 }
-                Err(_) => {
-                    set_status.set("RustFS launch command sent".to_string());
-                    let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
-                    push_log(
-                        set_app_logs,
-                        format!("[{}] Launch completed but response parsing failed", now),
-                        APP_LOG_CAPACITY,
-                    );
-                }
+            }
+        });
+    };
+
+    let restart_rustfs = move |_| {
+        set_is_running.set(true);
+        set_status.set("Restarting RustFS...".to_string());
+
+        let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+        push_log(
+            set_app_logs,
+            format!("[{}] Restart button clicked", now),
+            APP_LOG_CAPACITY,
+        );
+
+        spawn_local(async move {
+            if !is_tauri() {
+                set_status.set("Error: Not running in Tauri environment".to_string());
+                set_is_running.set(false);
+                return;
+            }
+
+            let current_config = config.get_untracked();
+            if check_fields(current_config.clone()).await {
+                restart_with_config(current_config, set_status, set_app_logs).await;
             }
             set_is_running.set(false);
         });
@@ -296,42 +978,188 @@ pub fn App() -> impl IntoView {
             <div class="header">
                 <h1>"RustFS Launcher"</h1>
                 <p class="subtitle">"Simple launcher for RustFS project"</p>
+                <span class=move || status_badge_class(&rustfs_status.get())>
+                    {move || status_label(&rustfs_status.get())}
+                </span>
+                <Show when=move || rustfs_status.get().is_active() && health.get().is_some()>
+                    <span class=move || {
+                        health.get().map(|h| health_badge_class(&h)).unwrap_or("status-badge stopped")
+                    }>
+                        {move || health.get().map(|h| health_label(&h)).unwrap_or_default()}
+                    </span>
+                </Show>
             </div>
 
             <form class="config-form" on:submit=launch_rustfs>
                 <div class="form-group">
-                    <label for="data-path">"Data Path" <span class="required">"*"</span></label>
+                    <label for="profile">"Profile"</label>
                     <div class="path-input-group">
+                        <select
+                            id="profile"
+                            prop:value=move || profiles.get().last_used.unwrap_or_default()
+                            on:change=select_profile
+                        >
+                            <Show when=move || profiles.get().names.is_empty()>
+                                <option value="" disabled=true>"No saved profiles"</option>
+                            </Show>
+                            <For
+                                each=move || profiles.get().names
+                                key=|name| name.clone()
+                                let:name
+                            >
+                                <option value=name.clone()>{name.clone()}</option>
+                            </For>
+                        </select>
+                        <button type="button" class="browse-btn" on:click=new_profile>"New"</button>
+                        <button
+                            type="button"
+                            class="browse-btn"
+                            disabled=move || profiles.get().last_used.is_none()
+                            on:click=rename_profile
+                        >
+                            "Rename"
+                        </button>
+                        <button
+                            type="button"
+                            class="browse-btn"
+                            disabled=move || profiles.get().last_used.is_none()
+                            on:click=duplicate_profile
+                        >
+                            "Duplicate"
+                        </button>
+                        <button
+                            type="button"
+                            class="browse-btn"
+                            disabled=move || profiles.get().last_used.is_none()
+                            on:click=delete_profile
+                        >
+                            "Delete"
+                        </button>
+                    </div>
+                    <div class="path-input-group profile-file-actions">
+                        <button type="button" class="browse-btn" on:click=import_config>
+                            "Import…"
+                        </button>
+                        <button type="button" class="browse-btn" on:click=export_config>
+                            "Export…"
+                        </button>
+                    </div>
+                </div>
+
+                <div class="form-group">
+                    <label>"Data Volumes" <span class="required">"*"</span></label>
+                    <For
+                        each=move || 0..config.get().data_volumes.len()
+                        key=|index| *index
+                        let:index
+                    >
+                        <div class="path-input-group volume-row">
+                            <input
+                                type="text"
+                                placeholder="/data/vol{1...4} or select a directory..."
+                                prop:value=move || {
+                                    config.get().data_volumes.get(index).cloned().unwrap_or_default()
+                                }
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    set_config.update(|c| {
+                                        if let Some(volume) = c.data_volumes.get_mut(index) {
+                                            *volume = value;
+                                        }
+                                    });
+                                }
+                            />
+                            <button
+                                type="button"
+                                class="browse-btn"
+                                on:click=move |_| select_folder(index)
+                            >
+                                "Browse"
+                            </button>
+                            <button
+                                type="button"
+                                class="kv-remove"
+                                on:click=move |_| set_config.update(|c| {
+                                    if index < c.data_volumes.len() {
+                                        c.data_volumes.remove(index);
+                                    }
+                                })
+                            >
+                                "✕"
+                            </button>
+                        </div>
+                    </For>
+                    <button
+                        type="button"
+                        class="kv-add"
+                        on:click=move |_| set_config.update(|c| c.data_volumes.push(String::new()))
+                    >
+                        "+ Add volume"
+                    </button>
+                    <div class="volume-builder">
                         <input
-                            id="data-path"
                             type="text"
-                            placeholder="Select data directory..."
-                            prop:value=move || config.get().data_path
+                            placeholder="Parent directory for an erasure set..."
+                            prop:value=move || volume_base.get()
+                            on:input=move |ev| set_volume_base.set(event_target_value(&ev))
+                        />
+                        <button type="button" class="browse-btn" on:click=select_volume_base>
+                            "Browse"
+                        </button>
+                        <input
+                            type="number"
+                            class="volume-count"
+                            min="1"
+                            max="32"
+                            title="Number of volumes"
+                            prop:value=move || volume_count.get().to_string()
                             on:input=move |ev| {
-                                let value = event_target_value(&ev);
-                                set_config.update(|c| c.data_path = value);
+                                if let Ok(count) = event_target_value(&ev).parse() {
+                                    set_volume_count.set(count);
+                                }
                             }
                         />
-                        <button type="button" class="browse-btn" on:click=select_folder>
-                            "Browse"
+                        <button type="button" class="kv-add" on:click=create_volumes>
+                            "Create volumes"
                         </button>
                     </div>
+                    {field_error("data_volumes")}
                 </div>
 
                 <div class="form-row">
                     <div class="form-group">
-                        <label for="port">"Port"</label>
-                        <input
-                            id="port"
-                            type="number"
-                            placeholder="8080"
-                            prop:value=move || config.get().port.map(|p| p.to_string()).unwrap_or_default()
-                            on:input=move |ev| {
-                                let value = event_target_value(&ev);
-                                let port = if value.is_empty() { None } else { value.parse().ok() };
-                                set_config.update(|c| c.port = port);
-                            }
-                        />
+                        <label for="port">
+                            {move || if config.get().auto_port { "Preferred Port" } else { "Port" }}
+                        </label>
+                        <div class="path-input-group">
+                            <input
+                                id="port"
+                                type="number"
+                                placeholder="9000"
+                                prop:value=move || config.get().port.map(|p| p.to_string()).unwrap_or_default()
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    let port = if value.is_empty() { None } else { value.parse().ok() };
+                                    set_config.update(|c| c.port = port);
+                                }
+                            />
+                            <button type="button" class="browse-btn" on:click=suggest_port>
+                                "Suggest"
+                            </button>
+                        </div>
+                        {field_error("port")}
+                        <div class="checkbox-group">
+                            <input
+                                id="auto-port"
+                                type="checkbox"
+                                prop:checked=move || config.get().auto_port
+                                on:change=move |ev| {
+                                    let checked = event_target_checked(&ev);
+                                    set_config.update(|c| c.auto_port = checked);
+                                }
+                            />
+                            <label for="auto-port">"Auto (pick a free port at launch)"</label>
+                        </div>
                     </div>
                     <div class="form-group">
                         <label for="host">"Host"</label>
@@ -346,9 +1174,29 @@ pub fn App() -> impl IntoView {
                                 set_config.update(|c| c.host = host);
                             }
                         />
+                        {field_error("host")}
                     </div>
                 </div>
 
+                <Show when=move || {
+                    let config = config.get();
+                    config.is_exposed() && config.uses_default_credentials()
+                }>
+                    <div class="credential-warning" role="alert">
+                        <strong>"Default credentials on a network-facing host"</strong>
+                        <p>
+                            {move || format!(
+                                "Anyone who can reach {} can sign in with the public \"{}\" keys. Generate new keys before launching.",
+                                config.get().address(),
+                                DEFAULT_CREDENTIAL
+                            )}
+                        </p>
+                        <button type="button" on:click=move |_| generate_keys()>
+                            "Generate Keys"
+                        </button>
+                    </div>
+                </Show>
+
                 <div class="form-row">
                     <div class="form-group">
                         <label for="access-key">"Access Key"</label>
@@ -363,29 +1211,47 @@ pub fn App() -> impl IntoView {
                                 set_config.update(|c| c.access_key = access_key);
                             }
                         />
+                        {field_error("access_key")}
                     </div>
                     <div class="form-group">
                         <label for="secret-key">"Secret Key"</label>
-                        <div class="input-with-toggle">
-                            <input
-                                id="secret-key"
-                                type=move || if show_secret.get() { "text" } else { "password" }
-                                placeholder="rustfsadmin"
-                                prop:value=move || config.get().secret_key.unwrap_or_default()
-                                on:input=move |ev| {
-                                    let value = event_target_value(&ev);
-                                    let secret_key = if value.is_empty() { None } else { Some(value) };
-                                    set_config.update(|c| c.secret_key = secret_key);
-                                }
-                            />
+                        <div class="path-input-group">
+                            <div class="input-with-toggle">
+                                <input
+                                    id="secret-key"
+                                    type=move || if show_secret.get() { "text" } else { "password" }
+                                    placeholder=move || match config.get().secret_ref {
+                                        Some(secret_ref) if secret_ref.backend == SecretBackend::Vault => {
+                                            "Saved in vault"
+                                        }
+                                        Some(_) => "Saved in OS keyring",
+                                        None => "rustfsadmin",
+                                    }
+                                    prop:value=move || config.get().secret_key.unwrap_or_default()
+                                    on:input=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        let secret_key = if value.is_empty() { None } else { Some(value) };
+                                        set_config.update(|c| c.secret_key = secret_key);
+                                    }
+                                />
+                                <button
+                                    type="button"
+                                    class="toggle-visibility"
+                                    on:click=move |_| set_show_secret.update(|show| *show = !*show)
+                                >
+                                    {move || if show_secret.get() { "🙈" } else { "👁️" }}
+                                </button>
+                            </div>
                             <button
                                 type="button"
-                                class="toggle-visibility"
-                                on:click=move |_| set_show_secret.update(|show| *show = !*show)
+                                class="browse-btn"
+                                title="Generate random access and secret keys"
+                                on:click=move |_| generate_keys()
                             >
-                                {move || if show_secret.get() { "🙈" } else { "👁️" }}
+                                "Generate"
                             </button>
                         </div>
+                        {field_error("secret_key")}
                     </div>
                 </div>
 
@@ -404,16 +1270,438 @@ pub fn App() -> impl IntoView {
                             <label for="console-enable">"Enable Console"</label>
                         </div>
                     </div>
+                    <div class="form-group">
+                        <label for="shutdown-timeout">"Shutdown Grace Period (s)"</label>
+                        <input
+                            id="shutdown-timeout"
+                            type="number"
+                            min="0"
+                            placeholder="10"
+                            prop:value=move || config.get().shutdown_timeout_secs.to_string()
+                            on:input=move |ev| {
+                                let value = event_target_value(&ev);
+                                if let Ok(secs) = value.parse() {
+                                    set_config.update(|c| c.shutdown_timeout_secs = secs);
+                                }
+                            }
+                        />
+                    </div>
+                </div>
+
+                <div class="form-row">
+                    <div class="form-group">
+                        <label for="restart-policy">"Restart Policy"</label>
+                        <select
+                            id="restart-policy"
+                            prop:value=move || config.get().restart_policy.as_str()
+                            on:change=move |ev| {
+                                let policy = RestartPolicy::from_value(&event_target_value(&ev));
+                                set_config.update(|c| c.restart_policy = policy);
+                            }
+                        >
+                            <option value="never">"Never"</option>
+                            <option value="on-failure">"On failure"</option>
+                            <option value="always">"Always"</option>
+                        </select>
+                    </div>
+                    <div class="form-group">
+                        <label for="max-restarts">"Max Restarts"</label>
+                        <input
+                            id="max-restarts"
+                            type="number"
+                            min="0"
+                            placeholder="5"
+                            disabled=move || config.get().restart_policy == RestartPolicy::Never
+                            prop:value=move || config.get().max_restarts.to_string()
+                            on:input=move |ev| {
+                                let value = event_target_value(&ev);
+                                if let Ok(max) = value.parse() {
+                                    set_config.update(|c| c.max_restarts = max);
+                                }
+                            }
+                        />
+                    </div>
+                </div>
+
+                <div class="form-row">
+                    <div class="form-group">
+                        <label for="restart-backoff">"Initial Restart Delay (s)"</label>
+                        <input
+                            id="restart-backoff"
+                            type="number"
+                            min="0"
+                            placeholder="1"
+                            disabled=move || config.get().restart_policy == RestartPolicy::Never
+                            prop:value=move || config.get().restart_backoff_secs.to_string()
+                            on:input=move |ev| {
+                                let value = event_target_value(&ev);
+                                if let Ok(secs) = value.parse() {
+                                    set_config.update(|c| c.restart_backoff_secs = secs);
+                                }
+                            }
+                        />
+                    </div>
+                    <div class="form-group">
+                        <label for="ready-timeout">"Startup Timeout (s)"</label>
+                        <input
+                            id="ready-timeout"
+                            type="number"
+                            min="1"
+                            placeholder="30"
+                            prop:value=move || config.get().ready_timeout_secs.to_string()
+                            on:input=move |ev| {
+                                let value = event_target_value(&ev);
+                                if let Ok(secs) = value.parse() {
+                                    set_config.update(|c| c.ready_timeout_secs = secs);
+                                }
+                            }
+                        />
+                    </div>
+                </div>
+
+                <details class="advanced">
+                    <summary>"Advanced"</summary>
+
+                    <div class="form-group">
+                        <label for="binary-path">"RustFS Binary"</label>
+                        <div class="path-input-group">
+                            <input
+                                id="binary-path"
+                                type="text"
+                                placeholder="Bundled binary"
+                                prop:value=move || config.get().binary_path.unwrap_or_default()
+                                on:input=move |ev| {
+                                    let value = optional(event_target_value(&ev));
+                                    set_config.update(|c| c.binary_path = value);
+                                }
+                            />
+                            <button type="button" class="browse-btn" on:click=select_binary>
+                                "Browse"
+                            </button>
+                        </div>
+                    </div>
+
+                    <div class="form-group">
+                        <label for="credential-delivery">"Pass Credentials Via"</label>
+                        <select
+                            id="credential-delivery"
+                            prop:value=move || config.get().credential_delivery.as_str()
+                            on:change=move |ev| {
+                                let delivery = CredentialDelivery::from_value(&event_target_value(&ev));
+                                set_config.update(|c| c.credential_delivery = delivery);
+                            }
+                        >
+                            <option value="env">"Environment variables"</option>
+                            <option value="argv">"Command-line arguments (visible in ps)"</option>
+                        </select>
+                    </div>
+
+                    <div class="form-group">
+                        <label for="secret-backend">"Save Secret Key In"</label>
+                        <select
+                            id="secret-backend"
+                            prop:value=move || config.get().secret_backend.as_str()
+                            on:change=move |ev| {
+                                let backend = SecretBackend::from_value(&event_target_value(&ev));
+                                set_config.update(|c| c.secret_backend = backend);
+                            }
+                        >
+                            <option value="keyring">"OS keyring"</option>
+                            <option value="vault">"Encrypted vault (passphrase)"</option>
+                        </select>
+                    </div>
+
+                    <Show when=uses_vault>
+                        <div class="form-group">
+                            <label for="vault-passphrase">
+                                {move || if vault.get().exists { "Vault Passphrase" } else { "New Vault Passphrase" }}
+                            </label>
+                            <Show
+                                when=move || !vault.get().unlocked
+                                fallback=move || view! {
+                                    <div class="path-input-group">
+                                        <span class="vault-unlocked">"Vault unlocked"</span>
+                                        <button type="button" class="browse-btn" on:click=lock_vault>
+                                            "Lock"
+                                        </button>
+                                    </div>
+                                }
+                            >
+                                <div class="path-input-group">
+                                    <input
+                                        id="vault-passphrase"
+                                        type="password"
+                                        prop:value=move || vault_passphrase.get()
+                                        on:input=move |ev| set_vault_passphrase.set(event_target_value(&ev))
+                                    />
+                                    <button type="button" class="browse-btn" on:click=unlock_vault>
+                                        {move || if vault.get().exists { "Unlock" } else { "Create" }}
+                                    </button>
+                                </div>
+                            </Show>
+                        </div>
+                    </Show>
+
+                    <div class="form-row">
+                        <div class="form-group">
+                            <label for="console-host">"Console Host"</label>
+                            <input
+                                id="console-host"
+                                type="text"
+                                placeholder="same as Host"
+                                disabled=move || !config.get().console_enable
+                                prop:value=move || config.get().console_host.unwrap_or_default()
+                                on:input=move |ev| {
+                                    let value = optional(event_target_value(&ev));
+                                    set_config.update(|c| c.console_host = value);
+                                }
+                            />
+                            {field_error("console_host")}
+                        </div>
+                        <div class="form-group">
+                            <label for="console-port">"Console Port"</label>
+                            <input
+                                id="console-port"
+                                type="number"
+                                placeholder="9001"
+                                disabled=move || !config.get().console_enable
+                                prop:value=move || config.get().console_port.map(|p| p.to_string()).unwrap_or_default()
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    let port = if value.is_empty() { None } else { value.parse().ok() };
+                                    set_config.update(|c| c.console_port = port);
+                                }
+                            />
+                            {field_error("console_port")}
+                        </div>
+                    </div>
+
+                    <div class="form-group">
+                        <label for="tls-path">"TLS Directory"</label>
+                        <div class="path-input-group">
+                            <input
+                                id="tls-path"
+                                type="text"
+                                placeholder="Serve plain HTTP"
+                                prop:value=move || config.get().tls_path.unwrap_or_default()
+                                on:input=move |ev| {
+                                    let value = optional(event_target_value(&ev));
+                                    set_config.update(|c| c.tls_path = value);
+                                }
+                            />
+                            <button type="button" class="browse-btn" on:click=select_tls_folder>
+                                "Browse"
+                            </button>
+                            <button
+                                type="button"
+                                class="browse-btn"
+                                title="Issue a certificate for Host, localhost and 127.0.0.1 from a local CA"
+                                on:click=generate_certificate
+                            >
+                                "Generate"
+                            </button>
+                        </div>
+                        {field_error("tls_path")}
+                        {move || ca_fingerprint.get().map(|fingerprint| view! {
+                            <div class="tls-ca">
+                                <span>"Local CA SHA-256"</span>
+                                <code>{fingerprint}</code>
+                                <button type="button" class="browse-btn" on:click=export_ca>
+                                    "Export CA"
+                                </button>
+                            </div>
+                        })}
+                    </div>
+
+                    <div class="form-row">
+                        <div class="form-group">
+                            <label for="region">"Region"</label>
+                            <input
+                                id="region"
+                                type="text"
+                                placeholder="us-east-1"
+                                prop:value=move || config.get().region.unwrap_or_default()
+                                on:input=move |ev| {
+                                    let value = optional(event_target_value(&ev));
+                                    set_config.update(|c| c.region = value);
+                                }
+                            />
+                            {field_error("region")}
+                        </div>
+                        <div class="form-group">
+                            <label for="server-domains">"Server Domains"</label>
+                            <input
+                                id="server-domains"
+                                type="text"
+                                placeholder="s3.local,s3.example.com"
+                                prop:value=move || config.get().server_domains.join(",")
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    let domains = if value.is_empty() {
+                                        Vec::new()
+                                    } else {
+                                        value.split(',').map(str::to_string).collect()
+                                    };
+                                    set_config.update(|c| c.server_domains = domains);
+                                }
+                            />
+                            {field_error("server_domains")}
+                        </div>
+                    </div>
+
+                    <div class="form-group">
+                        <label for="obs-endpoint">"Observability Endpoint"</label>
+                        <input
+                            id="obs-endpoint"
+                            type="text"
+                            placeholder="http://localhost:4317"
+                            prop:value=move || config.get().obs_endpoint.unwrap_or_default()
+                            on:input=move |ev| {
+                                let value = optional(event_target_value(&ev));
+                                set_config.update(|c| c.obs_endpoint = value);
+                            }
+                        />
+                        {field_error("obs_endpoint")}
+                    </div>
+                </details>
+
+                <div class="form-group">
+                    <label>"Extra Arguments"</label>
+                    <For
+                        each=move || 0..config.get().extra_args.len()
+                        key=|index| *index
+                        let:index
+                    >
+                        <div class="kv-row">
+                            <input
+                                type="text"
+                                placeholder="--flag=value"
+                                prop:value=move || {
+                                    config.get().extra_args.get(index).cloned().unwrap_or_default()
+                                }
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    set_config.update(|c| {
+                                        if let Some(arg) = c.extra_args.get_mut(index) {
+                                            *arg = value;
+                                        }
+                                    });
+                                }
+                            />
+                            <button
+                                type="button"
+                                class="kv-remove"
+                                on:click=move |_| set_config.update(|c| {
+                                    if index < c.extra_args.len() {
+                                        c.extra_args.remove(index);
+                                    }
+                                })
+                            >
+                                "✕"
+                            </button>
+                        </div>
+                    </For>
+                    <button
+                        type="button"
+                        class="kv-add"
+                        on:click=move |_| set_config.update(|c| c.extra_args.push(String::new()))
+                    >
+                        "+ Add argument"
+                    </button>
+                    {field_error("extra_args")}
+                </div>
+
+                <div class="form-group">
+                    <label>"Environment Variables"</label>
+                    <For
+                        each=move || 0..env_rows.get().len()
+                        key=|index| *index
+                        let:index
+                    >
+                        <div class="kv-row">
+                            <input
+                                type="text"
+                                placeholder="NAME"
+                                prop:value=move || {
+                                    env_rows.get().get(index).map(|(name, _)| name.clone()).unwrap_or_default()
+                                }
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    let mut rows = env_rows.get_untracked();
+                                    if let Some(row) = rows.get_mut(index) {
+                                        row.0 = value;
+                                    }
+                                    sync_env(rows);
+                                }
+                            />
+                            <input
+                                type="text"
+                                placeholder="value"
+                                prop:value=move || {
+                                    env_rows.get().get(index).map(|(_, value)| value.clone()).unwrap_or_default()
+                                }
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    let mut rows = env_rows.get_untracked();
+                                    if let Some(row) = rows.get_mut(index) {
+                                        row.1 = value;
+                                    }
+                                    sync_env(rows);
+                                }
+                            />
+                            <button
+                                type="button"
+                                class="kv-remove"
+                                on:click=move |_| {
+                                    let mut rows = env_rows.get_untracked();
+                                    if index < rows.len() {
+                                        rows.remove(index);
+                                    }
+                                    sync_env(rows);
+                                }
+                            >
+                                "✕"
+                            </button>
+                        </div>
+                    </For>
+                    <button
+                        type="button"
+                        class="kv-add"
+                        on:click=move |_| {
+                            let mut rows = env_rows.get_untracked();
+                            rows.push((String::new(), String::new()));
+                            sync_env(rows);
+                        }
+                    >
+                        "+ Add variable"
+                    </button>
+                    {field_error("env")}
                 </div>
 
                 <div class="form-actions">
                     <button
                         type="submit"
                         class="launch-btn"
-                        disabled=move || is_running.get() || config.get().data_path.is_empty()
+                        disabled=move || {
+                            is_running.get() || rustfs_status.get().is_active()
+                                || config.get().data_volumes.iter().all(|v| v.trim().is_empty())
+                        }
                     >
                         { move || if is_running.get() { "Launching..." } else { "Launch RustFS" } }
                     </button>
+                    <Show when=move || rustfs_status.get().is_active()>
+                        <button
+                            type="button"
+                            class="restart-btn"
+                            disabled=move || is_running.get()
+                            on:click=restart_rustfs
+                        >
+                            "Restart with Current Settings"
+                        </button>
+                        <button type="button" class="stop-btn" on:click=stop_rustfs>
+                            "Stop RustFS"
+                        </button>
+                    </Show>
                 </div>
             </form>
 
@@ -438,6 +1726,16 @@ pub fn App() -> impl IntoView {
                         >
                             "RustFS Output"
                         </button>
+                        <button
+                            class="log-tab"
+                            class:active=move || current_log_type.get() == LogType::Health
+                            on:click=move |_| {
+                                set_current_log_type.set(LogType::Health);
+                                spawn_local(fetch_health_history(set_health_history, set_status));
+                            }
+                        >
+                            "Health"
+                        </button>
                     </div>
                     <div class="log-output" node_ref=logs_ref>
                         <For
@@ -445,6 +1743,7 @@ pub fn App() -> impl IntoView {
                                 match current_log_type.get() {
                                     LogType::App => app_logs.get(),
                                     LogType::RustFS => rustfs_logs.get(),
+                                    LogType::Health => health_history.get(),
                                 }
                                 .into_iter()
                                 .collect::<Vec<_>>()
@@ -458,6 +1757,7 @@ pub fn App() -> impl IntoView {
                             match current_log_type.get() {
                                 LogType::App => app_logs.get().is_empty(),
                                 LogType::RustFS => rustfs_logs.get().is_empty(),
+                                LogType::Health => health_history.get().is_empty(),
                             }
                         }>
                             <div class="log-line">"No logs available"</div>
@@ -0,0 +1,202 @@
+//! Typed wrappers for the backend's Tauri commands.
+//!
+//! Each function mirrors one `#[tauri::command]` in `src-tauri/src/commands.rs`; a rejected
+//! invoke carries the backend `Error` as a [`CommandError`] and comes back as `IpcError::Backend`.
+
+use rustfs_launcher_shared::config::RustFsConfig;
+use rustfs_launcher_shared::health::HealthReport;
+use rustfs_launcher_shared::ipc::{
+    CommandError, CommandResponse, Credentials, ErrorCode, FieldError, ProfileList, TlsInfo,
+    VaultStatus,
+};
+use rustfs_launcher_shared::log::LogEntry;
+use rustfs_launcher_shared::status::RustFsStatus;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_namespace = ["window", "__TAURI__", "core"], js_name = invoke)]
+    async fn tauri_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IpcError {
+    /// The command ran and returned an error.
+    Backend(CommandError),
+    /// The reply did not match the expected type.
+    Decode(String),
+}
+
+impl IpcError {
+    /// The backend's error code, when the command itself failed.
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self {
+            IpcError::Backend(err) => Some(err.code),
+            IpcError::Decode(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for IpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpcError::Backend(err) => f.write_str(&err.message),
+            IpcError::Decode(message) => write!(f, "Unexpected response from backend: {}", message),
+        }
+    }
+}
+
+pub type IpcResult<T> = Result<T, IpcError>;
+
+/// Serializes values as plain JS objects; by default maps become `Map`s, which Tauri drops.
+pub fn to_js<T: Serialize>(value: &T) -> JsValue {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .unwrap()
+}
+
+fn command_error(err: JsValue) -> CommandError {
+    serde_wasm_bindgen::from_value(err.clone()).unwrap_or_else(|_| CommandError {
+        code: ErrorCode::Other,
+        message: err.as_string().unwrap_or_else(|| format!("{:?}", err)),
+    })
+}
+
+async fn invoke<A: Serialize, T: DeserializeOwned>(cmd: &str, args: &A) -> IpcResult<T> {
+    let value = tauri_invoke(cmd, to_js(args))
+        .await
+        .map_err(|err| IpcError::Backend(command_error(err)))?;
+    serde_wasm_bindgen::from_value(value).map_err(|err| IpcError::Decode(err.to_string()))
+}
+
+pub async fn launch_rustfs(config: &RustFsConfig) -> IpcResult<CommandResponse> {
+    invoke("launch_rustfs", &json!({ "config": config })).await
+}
+
+pub async fn stop_rustfs() -> IpcResult<CommandResponse> {
+    invoke("stop_rustfs", &json!({})).await
+}
+
+pub async fn restart_rustfs(config: &RustFsConfig) -> IpcResult<CommandResponse> {
+    invoke("restart_rustfs", &json!({ "config": config })).await
+}
+
+pub async fn validate_config(config: &RustFsConfig) -> IpcResult<Vec<FieldError>> {
+    invoke("validate_config", &json!({ "config": config })).await
+}
+
+pub async fn find_free_port(host: &str, preferred: u16) -> IpcResult<u16> {
+    invoke(
+        "find_free_port",
+        &json!({ "host": host, "preferred": preferred }),
+    )
+    .await
+}
+
+pub async fn create_data_volumes(pattern: &str) -> IpcResult<Vec<String>> {
+    invoke("create_data_volumes", &json!({ "pattern": pattern })).await
+}
+
+pub async fn generate_credentials() -> IpcResult<Credentials> {
+    invoke("generate_credentials", &json!({})).await
+}
+
+pub async fn get_app_logs() -> IpcResult<Vec<LogEntry>> {
+    invoke("get_app_logs", &json!({})).await
+}
+
+pub async fn get_rustfs_logs() -> IpcResult<Vec<LogEntry>> {
+    invoke("get_rustfs_logs", &json!({})).await
+}
+
+pub async fn get_rustfs_status() -> IpcResult<RustFsStatus> {
+    invoke("get_rustfs_status", &json!({})).await
+}
+
+pub async fn get_health(limit: usize) -> IpcResult<HealthReport> {
+    invoke("get_health", &json!({ "limit": limit })).await
+}
+
+pub async fn load_config() -> IpcResult<Option<RustFsConfig>> {
+    invoke("load_config", &json!({})).await
+}
+
+pub async fn save_config(config: &RustFsConfig) -> IpcResult<()> {
+    invoke("save_config", &json!({ "config": config })).await
+}
+
+pub async fn list_profiles() -> IpcResult<ProfileList> {
+    invoke("list_profiles", &json!({})).await
+}
+
+pub async fn load_profile(name: &str) -> IpcResult<RustFsConfig> {
+    invoke("load_profile", &json!({ "name": name })).await
+}
+
+pub async fn create_profile(name: &str, config: &RustFsConfig) -> IpcResult<()> {
+    invoke("create_profile", &json!({ "name": name, "config": config })).await
+}
+
+pub async fn rename_profile(name: &str, new_name: &str) -> IpcResult<()> {
+    invoke(
+        "rename_profile",
+        &json!({ "name": name, "newName": new_name }),
+    )
+    .await
+}
+
+pub async fn duplicate_profile(name: &str, new_name: &str) -> IpcResult<()> {
+    invoke(
+        "duplicate_profile",
+        &json!({ "name": name, "newName": new_name }),
+    )
+    .await
+}
+
+pub async fn delete_profile(name: &str) -> IpcResult<()> {
+    invoke("delete_profile", &json!({ "name": name })).await
+}
+
+pub async fn export_config(
+    path: &str,
+    config: &RustFsConfig,
+    include_secrets: bool,
+) -> IpcResult<()> {
+    invoke(
+        "export_config",
+        &json!({ "path": path, "config": config, "includeSecrets": include_secrets }),
+    )
+    .await
+}
+
+pub async fn import_config(path: &str) -> IpcResult<RustFsConfig> {
+    invoke("import_config", &json!({ "path": path })).await
+}
+
+pub async fn vault_status() -> IpcResult<VaultStatus> {
+    invoke("vault_status", &json!({})).await
+}
+
+pub async fn unlock_vault(passphrase: &str) -> IpcResult<()> {
+    invoke("unlock_vault", &json!({ "passphrase": passphrase })).await
+}
+
+pub async fn lock_vault() -> IpcResult<()> {
+    invoke("lock_vault", &json!({})).await
+}
+
+pub async fn generate_tls_certificate(host: &str) -> IpcResult<TlsInfo> {
+    invoke("generate_tls_certificate", &json!({ "host": host })).await
+}
+
+pub async fn tls_ca_fingerprint() -> IpcResult<Option<String>> {
+    invoke("tls_ca_fingerprint", &json!({})).await
+}
+
+pub async fn export_tls_ca(path: &str) -> IpcResult<()> {
+    invoke("export_tls_ca", &json!({ "path": path })).await
+}